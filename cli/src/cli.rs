@@ -127,6 +127,17 @@ pub struct Cli {
         help = "The name of the secret to use for the operator"
     )]
     pub secret_name: String,
+
+    /// The namespace the operator is installed into.
+    /// Example: cats-operator
+    /// Default: default
+    #[arg(
+        long,
+        env = "KUBERNETES_OPERATOR_NAMESPACE",
+        default_value = "default",
+        help = "The namespace the operator is installed into"
+    )]
+    pub namespace: String,
 }
 
 /// Available commands for the Kubernetes Operator Generator tool.
@@ -178,5 +189,17 @@ pub enum Commands {
         /// Generate the types.
         #[arg(short, long, help = "Generate the types")]
         types: bool,
+        /// Generate the CRD manifests without compiling the operator.
+        #[arg(
+            long,
+            help = "Generate the CRD manifests without compiling the operator"
+        )]
+        crds: bool,
+        /// Generate a Helm chart of the operator.
+        #[arg(long, help = "Generate a Helm chart of the operator")]
+        helm: bool,
+        /// Generate an OLM bundle of the operator.
+        #[arg(long, help = "Generate an OLM bundle of the operator")]
+        bundle: bool,
     },
 }
@@ -2,36 +2,85 @@ use crate::{
     config::Config,
     errors::AppError,
     templates::{
+        bundle::{Annotations, Dockerfile as BundleDockerfile},
         crdgen::Main as CrdGenTemplate,
+        filters,
+        helm::{
+            Chart as HelmChart, ClusterRole as HelmClusterRole,
+            ClusterRoleBinding as HelmClusterRoleBinding,
+            ConversionWebhook as HelmConversionWebhook, Deployment as HelmDeployment,
+            Helpers as HelmHelpers, PodDisruptionBudget as HelmPodDisruptionBudget,
+            Role as HelmRole, RoleBinding as HelmRoleBinding, Secret as HelmSecret,
+            ServiceAccount as HelmServiceAccount, Values as HelmValues,
+        },
         manifests::{
             examples::Example,
-            operator::{Deployment, Secret},
+            kustomize::{Kustomization, OverlayDev, OverlayProd},
+            operator::{ConversionWebhook, Deployment, NetworkPolicy, PodDisruptionBudget, Secret},
             rbac::{ClusterRole, ClusterRoleBinding, Role, RoleBinding, ServiceAccount},
         },
         operator::{
-            Controller, ControllerActionDelete, ControllerActionPost, ControllerActionPut, Main,
-            Type as TypeTemplate,
+            Controller, ControllerActionDelete, ControllerActionPost, ControllerActionPut,
+            Conversion, Main, Type as TypeTemplate,
         },
-        ClusterRoleTemplateIdentifiers, ControllerAttributes, Field, Metadata, Resource,
-        RoleTemplateIdentifiers,
+        ClusterRoleTemplateIdentifiers, ControllerAttributes, ConversionKind, ConversionVersion,
+        Field, Metadata, Resource, RoleTemplateIdentifiers, TypeConversion,
     },
     utils::{
-        extract_config_from_openapi, format_file, generate_template_file, get_ignored_files,
-        read_openapi_spec, uppercase_first_letter, upsert_line_to_file,
-        validate_openapi_kubernetes_extensions_exists, write_to_file,
+        create_directory_if_not_exists, extract_config_from_openapi, format_file,
+        generate_template_file, get_ignored_files, read_openapi_spec, uppercase_first_letter,
+        upsert_line_to_file, validate_openapi_kubernetes_extensions_exists, write_to_file,
     },
 };
 use askama::Template;
 use inflector::Inflector;
 use log::{error, info, warn};
-use openapiv3::{ReferenceOr, Schema, SchemaKind, Type};
+use openapiv3::{
+    OpenAPI, ReferenceOr, Schema, SchemaKind, StringFormat, Type, VariantOrUnknownOrEmpty,
+};
+use serde::Deserialize;
 use serde_json::{json, Map, Value};
 use std::{
     collections::{BTreeMap, HashMap, HashSet},
-    fs::File,
+    fs::{self, File},
+    net::IpAddr,
     path::Path,
 };
 
+const DEFAULT_DELETION_POLICY: &str = "Delete";
+const DELETION_POLICIES: [&str; 2] = ["Delete", "Orphan"];
+const DEFAULT_DRIFT_POLICY: &str = "Correct";
+const DRIFT_POLICIES: [&str; 3] = ["Correct", "Report", "Ignore"];
+const DEFAULT_SCOPE: &str = "Namespaced";
+const SCOPES: [&str; 2] = ["Namespaced", "Cluster"];
+/// Channel the OLM bundle is published to.
+const BUNDLE_CHANNEL: &str = "alpha";
+/// Version of the operator in the OLM bundle, the version of the generated operator crate.
+const BUNDLE_VERSION: &str = "0.1.0";
+/// Directory OLM mounts the certificate of the conversion webhook into.
+const OLM_WEBHOOK_CERTS_DIR: &str = "/tmp/k8s-webhook-server/serving-certs";
+/// Namespace the Helm chart is installed into, resolved when the chart is rendered.
+const HELM_NAMESPACE: &str = "{{ include \"operator.namespace\" . }}";
+
+/// The components `execute` generates, everything but the CRDs, the Helm chart and the OLM bundle
+/// when none is selected.
+pub struct Targets {
+    /// Generate all components.
+    pub all: bool,
+    /// Generate the manifest files.
+    pub manifests: bool,
+    /// Generate the controller files.
+    pub controllers: bool,
+    /// Generate the type files.
+    pub types: bool,
+    /// Generate the CRD manifests.
+    pub crds: bool,
+    /// Generate the Helm chart.
+    pub helm: bool,
+    /// Generate the OLM bundle.
+    pub bundle: bool,
+}
+
 /// Executes the generation process based on the provided OpenAPI file and flags.
 ///
 /// This function generates various components of a Kubernetes operator project
@@ -43,11 +92,7 @@ use std::{
 ///
 /// * `base_path` - A string slice that holds the base path for the project.
 /// * `openapi_file` - A string slice that holds the path to the OpenAPI file.
-/// * `all` - A boolean flag indicating whether to generate all components.
-/// * `lib` - A boolean flag indicating whether to generate the library files.
-/// * `manifests` - A boolean flag indicating whether to generate the manifest files.
-/// * `controllers` - A boolean flag indicating whether to generate the controller files.
-/// * `types` - A boolean flag indicating whether to generate the type files.
+/// * `targets` - The components to generate.
 ///
 /// # Returns
 ///
@@ -60,10 +105,7 @@ use std::{
 pub fn execute(
     base_path: &String,
     openapi_file: &String,
-    all: &bool,
-    manifests: &bool,
-    controllers: &bool,
-    types: &bool,
+    targets: &Targets,
 ) -> Result<(), AppError> {
     info!("Using OpenAPI file: {}", openapi_file);
 
@@ -72,6 +114,8 @@ pub fn execute(
     validate_openapi_kubernetes_extensions_exists(&openapi)?;
 
     let config: Config = extract_config_from_openapi(&openapi)?;
+    let install_crds = get_install_crds(&openapi)?;
+    let deployment_settings = get_deployment_settings(&openapi)?;
 
     let components = openapi
         .components
@@ -90,29 +134,66 @@ pub fn execute(
         })
         .collect();
 
+    let crd_versions = get_crd_versions(&schemas, &config.api_group, &config.api_version)?;
+    let versioned_schemas: HashSet<&String> = crd_versions
+        .values()
+        .flatten()
+        .map(|(name, _)| name)
+        .collect();
+
+    // Additional versions are served by the CRD of their hub kind.
     let mut schema_names = vec![];
     for (schema_name, _) in components.schemas.iter() {
+        if versioned_schemas.contains(schema_name) {
+            continue;
+        }
         schema_names.push(schema_name.to_lowercase().to_plural());
     }
 
+    let cluster_scoped_resources = get_cluster_scoped_resources(&schemas)?;
+    let resource_plurals = get_resource_plurals(&schemas)?;
+    let resource_group_versions =
+        get_resource_group_versions(&schemas, &config.api_group, &config.api_version)?;
+    let resource_crd_names = get_resource_crd_names(&resource_plurals, &resource_group_versions);
+
     let k8s_operator_dir = format!("{}/operator", base_path);
     let k8s_crdgen_dir = format!("{}/crdgen", base_path);
     let k8s_operator_types_dir = format!("{}/operator/src/types", base_path);
     let k8s_operator_controllers_dir = format!("{}/operator/src/controllers", base_path);
+    let k8s_manifests_dir = format!("{}/manifests", base_path);
     let k8s_manifests_rbac_dir = format!("{}/manifests/rbac", base_path);
     let k8s_manifests_operator_dir = format!("{}/manifests/operator", base_path);
     let k8s_manifests_examples_dir = format!("{}/manifests/examples", base_path);
+    let k8s_manifests_crds_dir = format!("{}/manifests/crds", base_path);
+    let k8s_charts_dir = format!("{}/charts", base_path);
+    let k8s_bundle_dir = format!("{}/bundle", base_path);
 
-    if *all || (!*manifests && !*controllers && !*types) {
+    let Targets {
+        all,
+        manifests,
+        controllers,
+        types,
+        crds,
+        helm,
+        bundle,
+    } = *targets;
+
+    if all || (!manifests && !controllers && !types && !crds && !helm && !bundle) {
         info!("Generating all manifests, controllers and types...");
-        generate_types(&k8s_operator_types_dir, &schemas, &config.resource_ref)?;
+        generate_types(
+            &k8s_operator_types_dir,
+            &schemas,
+            &config.resource_ref,
+            &config.api_group,
+            &config.api_version,
+        )?;
+        generate_conversion_file(&k8s_operator_dir, &schemas, &crd_versions, &config)?;
         let controllers = generate_controllers(
             base_path,
             &k8s_operator_controllers_dir,
             &schemas,
             paths.clone(),
-            config.include_tags.clone(),
-            config.resource_ref.clone(),
+            &config,
         )?;
         generate_main_file(
             &k8s_operator_dir,
@@ -120,50 +201,74 @@ pub fn execute(
             &config.api_version,
             controllers,
             schema_names.clone(),
+            cluster_scoped_resources.clone(),
+            &resource_crd_names,
         )?;
         generate_rbac_files(
             &k8s_manifests_rbac_dir,
             schema_names.clone(),
-            &config.api_group,
+            &cluster_scoped_resources,
+            &resource_plurals,
+            &resource_group_versions,
+            &config,
+            install_crds,
+        )?;
+        generate_operator_deployment_files(
+            &k8s_manifests_operator_dir,
+            &config,
+            !crd_versions.is_empty(),
+            install_crds,
+            &deployment_settings,
         )?;
-        generate_operator_deployment_files(&k8s_manifests_operator_dir, config.secret_name)?;
-        generate_crdgen_file(&k8s_crdgen_dir, schema_names.clone())?;
+        generate_crds(&k8s_manifests_crds_dir, &schemas, &config)?;
+        generate_crdgen_fixtures(&k8s_crdgen_dir, &schemas, &config)?;
+        generate_kustomize_files(&k8s_manifests_dir, &config)?;
+        generate_crdgen_file(&k8s_crdgen_dir, schema_names.clone(), &config.namespace)?;
         generate_examples(
             &k8s_manifests_examples_dir,
-            &config.example_metadata_spec_field_ref,
+            &schemas,
             components.examples.into_iter().collect(),
-            &config.api_group,
-            &config.api_version,
-            &config.resource_ref.clone(),
+            &config,
         )?;
         return Ok(());
     }
-    if *manifests {
+    if manifests {
         info!("Generating manifests...");
         generate_rbac_files(
             &k8s_manifests_rbac_dir,
             schema_names.clone(),
-            &config.api_group,
+            &cluster_scoped_resources,
+            &resource_plurals,
+            &resource_group_versions,
+            &config,
+            install_crds,
         )?;
-        generate_crdgen_file(&k8s_crdgen_dir, schema_names.clone())?;
+        generate_operator_deployment_files(
+            &k8s_manifests_operator_dir,
+            &config,
+            !crd_versions.is_empty(),
+            install_crds,
+            &deployment_settings,
+        )?;
+        generate_crds(&k8s_manifests_crds_dir, &schemas, &config)?;
+        generate_crdgen_fixtures(&k8s_crdgen_dir, &schemas, &config)?;
+        generate_kustomize_files(&k8s_manifests_dir, &config)?;
+        generate_crdgen_file(&k8s_crdgen_dir, schema_names.clone(), &config.namespace)?;
         generate_examples(
             &k8s_manifests_examples_dir,
-            &config.example_metadata_spec_field_ref,
-            components.examples.into_iter().collect(),
-            &config.api_group,
-            &config.api_version,
-            &config.resource_ref.clone(),
+            &schemas,
+            components.examples.clone().into_iter().collect(),
+            &config,
         )?;
     }
-    if *controllers {
+    if controllers {
         info!("Generating controllers...");
         let controllers = generate_controllers(
             base_path,
             &k8s_operator_controllers_dir,
             &schemas,
             paths.clone(),
-            config.include_tags.clone(),
-            config.resource_ref.clone(),
+            &config,
         )?;
         generate_main_file(
             &k8s_operator_dir,
@@ -171,28 +276,73 @@ pub fn execute(
             &config.api_version,
             controllers,
             schema_names.clone(),
+            cluster_scoped_resources.clone(),
+            &resource_crd_names,
         )?;
     }
-    if *types {
+    if types {
         info!("Generating the types...");
-        generate_types(&k8s_operator_types_dir, &schemas, &config.resource_ref)?;
+        generate_types(
+            &k8s_operator_types_dir,
+            &schemas,
+            &config.resource_ref,
+            &config.api_group,
+            &config.api_version,
+        )?;
+        generate_conversion_file(&k8s_operator_dir, &schemas, &crd_versions, &config)?;
+    }
+    if crds {
+        info!("Generating the CRDs...");
+        generate_crds(&k8s_manifests_crds_dir, &schemas, &config)?;
+        generate_crdgen_fixtures(&k8s_crdgen_dir, &schemas, &config)?;
+    }
+    if helm {
+        info!("Generating the Helm chart...");
+        generate_helm_chart(&k8s_charts_dir, &schemas, &config, &deployment_settings)?;
+    }
+    if bundle {
+        info!("Generating the OLM bundle...");
+        generate_bundle(
+            &k8s_bundle_dir,
+            &schemas,
+            components.examples.into_iter().collect(),
+            &config,
+            &deployment_settings,
+        )?;
     }
     Ok(())
 }
 
 /// Generates RBAC files based on the provided resources and Kubernetes operator group.
+///
+/// Namespaced resources are granted by the Role and cluster-scoped resources by
+/// the ClusterRole, which only writes CRDs when the operator installs them.
+/// Resources are named by their inflected plural, renamed to the plural of their
+/// CRD and grouped by the API group of their CRD.
 fn generate_rbac_files(
     directory: &str,
     resources: Vec<String>,
-    api_group: &str,
+    cluster_scoped_resources: &[String],
+    resource_plurals: &BTreeMap<String, String>,
+    resource_group_versions: &BTreeMap<String, (String, String)>,
+    config: &Config,
+    install_crds: bool,
 ) -> Result<(), AppError> {
     let base_path_rbac = Path::new(directory);
-
     generate_template_file(
         Role {
+            operator_name: config.operator_name.clone(),
+            namespace: config.namespace.clone(),
             identifiers: RoleTemplateIdentifiers {
-                api_group: api_group.to_string(),
-                resources: resources.clone(),
+                api_groups: group_rbac_resources(
+                    resources
+                        .iter()
+                        .filter(|resource| !cluster_scoped_resources.contains(resource))
+                        .collect(),
+                    resource_plurals,
+                    resource_group_versions,
+                    &config.api_group,
+                ),
             },
         },
         base_path_rbac,
@@ -200,44 +350,948 @@ fn generate_rbac_files(
     )?;
     generate_template_file(
         ClusterRole {
+            operator_name: config.operator_name.clone(),
             identifiers: ClusterRoleTemplateIdentifiers {
-                api_group: api_group.to_string(),
-                resources: resources.clone(),
+                api_groups: group_rbac_resources(
+                    resources
+                        .iter()
+                        .filter(|resource| cluster_scoped_resources.contains(resource))
+                        .collect(),
+                    resource_plurals,
+                    resource_group_versions,
+                    &config.api_group,
+                ),
+                install_crds,
             },
         },
         base_path_rbac,
         "clusterrole.yaml",
     )?;
-    generate_template_file(ServiceAccount {}, base_path_rbac, "serviceaccount.yaml")?;
-    generate_template_file(RoleBinding {}, base_path_rbac, "rolebinding.yaml")?;
     generate_template_file(
-        ClusterRoleBinding {},
+        ServiceAccount {
+            operator_name: config.operator_name.clone(),
+            namespace: config.namespace.clone(),
+        },
+        base_path_rbac,
+        "serviceaccount.yaml",
+    )?;
+    generate_template_file(
+        RoleBinding {
+            operator_name: config.operator_name.clone(),
+            namespace: config.namespace.clone(),
+        },
+        base_path_rbac,
+        "rolebinding.yaml",
+    )?;
+    generate_template_file(
+        ClusterRoleBinding {
+            operator_name: config.operator_name.clone(),
+            namespace: config.namespace.clone(),
+        },
         base_path_rbac,
         "clusterrolebinding.yaml",
     )?;
+    generate_template_file(
+        Kustomization {
+            resources: vec![
+                "serviceaccount.yaml".to_string(),
+                "role.yaml".to_string(),
+                "rolebinding.yaml".to_string(),
+                "clusterrole.yaml".to_string(),
+                "clusterrolebinding.yaml".to_string(),
+            ],
+        },
+        base_path_rbac,
+        "kustomization.yaml",
+    )?;
 
     Ok(())
 }
 
-/// Generates the operator deployment files.
+/// Groups RBAC resources by the API group of their CRD, named by the plural of their CRD.
+fn group_rbac_resources(
+    resources: Vec<&String>,
+    resource_plurals: &BTreeMap<String, String>,
+    resource_group_versions: &BTreeMap<String, (String, String)>,
+    api_group: &str,
+) -> BTreeMap<String, Vec<String>> {
+    let mut groups: BTreeMap<String, Vec<String>> = BTreeMap::new();
+    for resource in resources {
+        let group = resource_group_versions
+            .get(resource)
+            .map_or(api_group, |(group, _)| group.as_str());
+        let plural = resource_plurals.get(resource).unwrap_or(resource);
+        groups
+            .entry(group.to_string())
+            .or_default()
+            .push(plural.clone());
+    }
+    groups
+}
+
+/// Generates the operator deployment files, with a PodDisruptionBudget and a
+/// NetworkPolicy when configured.
 fn generate_operator_deployment_files(
     directory: &str,
-    secret_name: String,
+    config: &Config,
+    conversion_webhook: bool,
+    install_crds: bool,
+    settings: &DeploymentSettings,
 ) -> Result<(), AppError> {
     let base_path_operator = Path::new(directory);
+    let mut resources = vec!["deployment.yaml".to_string(), "secret.yaml".to_string()];
+
+    if conversion_webhook {
+        generate_template_file(
+            ConversionWebhook {
+                operator_name: config.operator_name.clone(),
+                namespace: config.namespace.clone(),
+            },
+            base_path_operator,
+            "conversion-webhook.yaml",
+        )?;
+        resources.push("conversion-webhook.yaml".to_string());
+    }
+    if let Some(budget) = &settings.pod_disruption_budget {
+        generate_template_file(
+            PodDisruptionBudget {
+                operator_name: config.operator_name.clone(),
+                namespace: config.namespace.clone(),
+                budget: budget.clone(),
+            },
+            base_path_operator,
+            "pod-disruption-budget.yaml",
+        )?;
+        resources.push("pod-disruption-budget.yaml".to_string());
+    }
+    if let Some(network_policy) = &settings.network_policy {
+        generate_template_file(
+            NetworkPolicy {
+                operator_name: config.operator_name.clone(),
+                namespace: config.namespace.clone(),
+                api_server_cidrs: network_policy.api_server_cidrs.clone(),
+                remote_host: network_policy.remote_host.clone(),
+                remote_port: network_policy.remote_port,
+                remote_cidrs: network_policy.remote_cidrs.clone(),
+            },
+            base_path_operator,
+            "network-policy.yaml",
+        )?;
+        resources.push("network-policy.yaml".to_string());
+    }
 
     generate_template_file(
-        Deployment {
-            secret_name: secret_name.clone(),
+        build_deployment(config, install_crds, settings),
+        base_path_operator,
+        "deployment.yaml",
+    )?;
+    generate_template_file(
+        Secret {
+            namespace: config.namespace.clone(),
+            secret_name: config.secret_name.clone(),
         },
         base_path_operator,
+        "secret.yaml",
+    )?;
+    generate_template_file(
+        Kustomization { resources },
+        base_path_operator,
+        "kustomization.yaml",
+    )?;
+
+    Ok(())
+}
+
+/// Builds the Deployment of the operator from its settings.
+fn build_deployment(
+    config: &Config,
+    install_crds: bool,
+    settings: &DeploymentSettings,
+) -> Deployment {
+    Deployment {
+        operator_name: config.operator_name.clone(),
+        namespace: config.namespace.clone(),
+        secret_name: config.secret_name.clone(),
+        install_crds,
+        resources: settings.resources.clone(),
+        pod_security_context: settings.pod_security_context.clone(),
+        security_context: settings.security_context.clone(),
+        affinity: settings.affinity.clone(),
+        topology_spread_constraints: settings.topology_spread_constraints.clone(),
+    }
+}
+
+/// Generates the kustomize base of the CRDs, RBAC and operator manifests, and the
+/// `dev` and `prod` overlays on top of it.
+///
+/// The overlays are only written when missing, so they can be adjusted to the
+/// environment without adding them to `.openapi-generator-ignore`.
+fn generate_kustomize_files(directory: &str, config: &Config) -> Result<(), AppError> {
+    let base_path = Path::new(directory).join("base");
+    create_directory_if_not_exists(&base_path);
+    generate_template_file(
+        Kustomization {
+            resources: vec![
+                "../crds".to_string(),
+                "../rbac".to_string(),
+                "../operator".to_string(),
+            ],
+        },
+        &base_path,
+        "kustomization.yaml",
+    )?;
+
+    let dev_path = Path::new(directory).join("overlays").join("dev");
+    if !dev_path.join("kustomization.yaml").exists() {
+        create_directory_if_not_exists(&dev_path);
+        generate_template_file(
+            OverlayDev {
+                operator_name: config.operator_name.clone(),
+                namespace: config.namespace.clone(),
+            },
+            &dev_path,
+            "kustomization.yaml",
+        )?;
+    }
+    let prod_path = Path::new(directory).join("overlays").join("prod");
+    if !prod_path.join("kustomization.yaml").exists() {
+        create_directory_if_not_exists(&prod_path);
+        generate_template_file(
+            OverlayProd {
+                namespace: config.namespace.clone(),
+                secret_name: config.secret_name.clone(),
+            },
+            &prod_path,
+            "kustomization.yaml",
+        )?;
+    }
+
+    Ok(())
+}
+
+/// Generates the conversion webhook serving the additional versions of every kind.
+fn generate_conversion_file(
+    directory: &str,
+    schemas: &HashMap<String, Schema>,
+    crd_versions: &BTreeMap<String, Vec<(String, String)>>,
+    config: &Config,
+) -> Result<(), AppError> {
+    let base_path = &Path::new(directory).join("src");
+    let file_path = base_path
+        .join("conversion.rs")
+        .to_string_lossy()
+        .to_string();
+    if get_ignored_files()?.contains(&file_path) {
+        return Ok(());
+    }
+
+    let mut kinds = vec![];
+    for (hub, versions) in crd_versions {
+        let schema = &schemas[hub];
+        let (api_group, api_version) =
+            get_schema_group_version(schema, &config.api_group, &config.api_version)?;
+        kinds.push(ConversionKind {
+            kind: uppercase_first_letter(hub),
+            module: hub.to_lowercase(),
+            crd_name: format!("{}.{}", get_schema_plural(hub, schema)?, api_group),
+            storage_version: get_storage_version(schema, &api_version, versions)?,
+            versions: versions
+                .iter()
+                .map(|(name, version)| ConversionVersion {
+                    type_name: uppercase_first_letter(name),
+                    module: name.to_lowercase(),
+                    api_version: format!("{}/{}", api_group, version),
+                })
+                .collect(),
+        });
+    }
+
+    let content: String = Conversion {
+        operator_name: config.operator_name.clone(),
+        kinds,
+    }
+    .render()?;
+    write_to_file(base_path, "conversion.rs", content)?;
+    format_file(base_path.join("conversion.rs").to_str().unwrap())
+}
+
+/// Generates the CRD manifests straight from the schemas, one file per CRD
+/// together with a kustomization listing them.
+///
+/// The CRDs mirror the ones `kube` derives from the generated types, so they
+/// can be produced without compiling the operator.
+pub fn generate_crds(
+    directory: &str,
+    schemas: &HashMap<String, Schema>,
+    config: &Config,
+) -> Result<(), AppError> {
+    let base_path = Path::new(directory);
+    create_directory_if_not_exists(base_path);
+
+    let mut crd_files = vec![];
+    for (file_name, crd) in build_crds(schemas, config, &config.namespace)? {
+        write_crd_file(base_path, &file_name, &crd)?;
+        crd_files.push(file_name);
+    }
+
+    let content = Kustomization {
+        resources: crd_files,
+    }
+    .render()?;
+    write_to_file(base_path, "kustomization.yaml", content)
+}
+
+/// Writes the CRDs generated from the OpenAPI schemas into `tests/crds` of the crdgen crate.
+///
+/// `k8s-crdgen` never writes there, so its tests compare these files with the CRDs
+/// derived from the types instead of with its own output in `manifests/crds`.
+pub fn generate_crdgen_fixtures(
+    directory: &str,
+    schemas: &HashMap<String, Schema>,
+    config: &Config,
+) -> Result<(), AppError> {
+    let base_path: &Path = &Path::new(directory).join("tests").join("crds");
+    create_directory_if_not_exists(base_path);
+
+    for (file_name, crd) in build_crds(schemas, config, &config.namespace)? {
+        write_crd_file(base_path, &file_name, &crd)?;
+    }
+    Ok(())
+}
+
+/// Writes a CRD with the header `k8s-crdgen` writes too, so both produce the same files.
+fn write_crd_file(base_path: &Path, file_name: &str, crd: &Value) -> Result<(), AppError> {
+    let content = format!(
+        "# This file is generated by kopgen. Do not edit manually. If you need to make adjustments add it to .openapi-generator-ignore file.\n---\n{}",
+        serde_yaml::to_string(crd)?.trim_end()
+    );
+    write_to_file(base_path, file_name, content)
+}
+
+/// Generates a Helm chart of the operator, with its CRDs, RBAC, Deployment and Secret.
+///
+/// The CRDs are templates of the chart, so conversions are routed to the webhook
+/// in the namespace the chart is installed into.
+pub fn generate_helm_chart(
+    directory: &str,
+    schemas: &HashMap<String, Schema>,
+    config: &Config,
+    settings: &DeploymentSettings,
+) -> Result<(), AppError> {
+    let chart_path = Path::new(directory).join(filters::dashcase(&config.operator_name)?);
+    let templates_path = chart_path.join("templates");
+    let crds_path = templates_path.join("crds");
+    create_directory_if_not_exists(&crds_path);
+
+    let crd_versions = get_crd_versions(schemas, &config.api_group, &config.api_version)?;
+    let (namespaced, cluster_scoped) = get_rbac_api_groups(schemas, config)?;
+
+    write_to_file(
+        &chart_path,
+        "Chart.yaml",
+        HelmChart {
+            operator_name: config.operator_name.clone(),
+            operator_author: config.operator_author.clone(),
+        }
+        .render()?,
+    )?;
+    write_to_file(
+        &chart_path,
+        "values.yaml",
+        HelmValues {
+            secret_name: config.secret_name.clone(),
+            resources: settings.resources.clone(),
+            pod_security_context: settings.pod_security_context.clone(),
+            security_context: settings.security_context.clone(),
+            affinity: settings.affinity.clone(),
+            topology_spread_constraints: settings.topology_spread_constraints.clone(),
+            pod_disruption_budget: settings.pod_disruption_budget.clone(),
+        }
+        .render()?,
+    )?;
+    write_to_file(&templates_path, "_helpers.tpl", HelmHelpers {}.render()?)?;
+    write_to_file(
+        &templates_path,
         "deployment.yaml",
+        HelmDeployment {
+            conversion_webhook: !crd_versions.is_empty(),
+        }
+        .render()?,
+    )?;
+    write_to_file(
+        &templates_path,
+        "poddisruptionbudget.yaml",
+        HelmPodDisruptionBudget {}.render()?,
     )?;
-    generate_template_file(Secret { secret_name }, base_path_operator, "secret.yaml")?;
+    write_to_file(&templates_path, "secret.yaml", HelmSecret {}.render()?)?;
+    write_to_file(
+        &templates_path,
+        "serviceaccount.yaml",
+        HelmServiceAccount {}.render()?,
+    )?;
+    write_to_file(
+        &templates_path,
+        "role.yaml",
+        HelmRole {
+            identifiers: RoleTemplateIdentifiers {
+                api_groups: namespaced,
+            },
+        }
+        .render()?,
+    )?;
+    write_to_file(
+        &templates_path,
+        "rolebinding.yaml",
+        HelmRoleBinding {}.render()?,
+    )?;
+    write_to_file(
+        &templates_path,
+        "clusterrole.yaml",
+        HelmClusterRole {
+            identifiers: RoleTemplateIdentifiers {
+                api_groups: cluster_scoped,
+            },
+        }
+        .render()?,
+    )?;
+    write_to_file(
+        &templates_path,
+        "clusterrolebinding.yaml",
+        HelmClusterRoleBinding {}.render()?,
+    )?;
+    let conversion_webhook_path = templates_path.join("conversion-webhook.yaml");
+    if !crd_versions.is_empty() {
+        write_to_file(
+            &templates_path,
+            "conversion-webhook.yaml",
+            HelmConversionWebhook {}.render()?,
+        )?;
+    } else if conversion_webhook_path.exists() {
+        // Left over from a spec serving additional versions, it would still install the cert-manager resources.
+        fs::remove_file(conversion_webhook_path)?;
+    }
+
+    for (file_name, mut crd) in build_crds(schemas, config, HELM_NAMESPACE)? {
+        crd["metadata"]["annotations"]["helm.sh/resource-policy"] = json!("keep");
+        let content = format!(
+            "# This file is generated by kopgen. Do not edit manually. If you need to make adjustments add it to .openapi-generator-ignore file.\n{{{{- if .Values.crds.enabled }}}}\n---\n{}{{{{- end }}}}",
+            serde_yaml::to_string(&crd)?
+        );
+        write_to_file(&crds_path, &file_name, content)?;
+    }
 
     Ok(())
 }
 
+/// Generates an OLM bundle of the operator, with a ClusterServiceVersion owning its CRDs.
+///
+/// The install permissions and the deployment are taken from the RBAC and operator
+/// manifests, the examples of the CRDs from the example manifests. Conversions of
+/// kinds served in additional versions go through a webhook managed by OLM.
+pub fn generate_bundle(
+    directory: &str,
+    schemas: &HashMap<String, Schema>,
+    examples: HashMap<String, ReferenceOr<openapiv3::Example>>,
+    config: &Config,
+    settings: &DeploymentSettings,
+) -> Result<(), AppError> {
+    let package = filters::dashcase(&config.operator_name)?;
+    let manifests_path = Path::new(directory).join("manifests");
+    let metadata_path = Path::new(directory).join("metadata");
+    create_directory_if_not_exists(&manifests_path);
+    create_directory_if_not_exists(&metadata_path);
+
+    let mut owned = vec![];
+    let mut conversion_crds = vec![];
+    for (file_name, mut crd) in build_crds(schemas, config, &config.namespace)? {
+        // OLM configures the conversion webhook and its certificate itself.
+        if let Some(crd) = crd.as_object_mut() {
+            crd["metadata"]
+                .as_object_mut()
+                .map(|metadata| metadata.remove("annotations"));
+            if crd["spec"]
+                .as_object_mut()
+                .and_then(|spec| spec.remove("conversion"))
+                .is_some()
+            {
+                conversion_crds.push(crd["metadata"]["name"].clone());
+            }
+        }
+
+        let kind = crd["spec"]["names"]["kind"].as_str().unwrap_or_default();
+        let description = schemas
+            .iter()
+            .find(|(name, _)| uppercase_first_letter(name) == kind)
+            .and_then(|(_, schema)| schema.schema_data.description.clone())
+            .unwrap_or_else(|| format!("{} managed by the {}.", kind, config.operator_name));
+        for version in crd["spec"]["versions"].as_array().into_iter().flatten() {
+            owned.push(json!({
+                "name": crd["metadata"]["name"],
+                "version": version["name"],
+                "kind": kind,
+                "displayName": kind,
+                "description": description,
+            }));
+        }
+
+        write_crd_file(&manifests_path, &file_name, &crd)?;
+    }
+
+    let (namespaced, cluster_scoped) = get_rbac_api_groups(schemas, config)?;
+    let role: Value = serde_yaml::from_str(
+        &Role {
+            operator_name: config.operator_name.clone(),
+            namespace: config.namespace.clone(),
+            identifiers: RoleTemplateIdentifiers {
+                api_groups: namespaced,
+            },
+        }
+        .render()?,
+    )?;
+    let cluster_role: Value = serde_yaml::from_str(
+        &ClusterRole {
+            operator_name: config.operator_name.clone(),
+            identifiers: ClusterRoleTemplateIdentifiers {
+                api_groups: cluster_scoped,
+                install_crds: false,
+            },
+        }
+        .render()?,
+    )?;
+    let service_account: Value = serde_yaml::from_str(
+        &ServiceAccount {
+            operator_name: config.operator_name.clone(),
+            namespace: config.namespace.clone(),
+        }
+        .render()?,
+    )?;
+    let service_account_name = &service_account["metadata"]["name"];
+    let mut deployment: Value =
+        serde_yaml::from_str(&build_deployment(config, false, settings).render()?)?;
+
+    let pod_spec = &mut deployment["spec"]["template"]["spec"];
+    pod_spec
+        .as_object_mut()
+        .map(|pod_spec| pod_spec.remove("volumes"));
+    let container = &mut pod_spec["containers"][0];
+    container
+        .as_object_mut()
+        .map(|container| container.remove("volumeMounts"));
+    if let Some(env) = container["env"].as_array_mut() {
+        env.push(json!({
+            "name": "WATCH_NAMESPACE",
+            "valueFrom": {
+                "fieldRef": { "fieldPath": "metadata.annotations['olm.targetNamespaces']" },
+            },
+        }));
+        if !conversion_crds.is_empty() {
+            env.push(json!({
+                "name": "WEBHOOK_CERT_PATH",
+                "value": format!("{}/tls.crt", OLM_WEBHOOK_CERTS_DIR),
+            }));
+            env.push(json!({
+                "name": "WEBHOOK_KEY_PATH",
+                "value": format!("{}/tls.key", OLM_WEBHOOK_CERTS_DIR),
+            }));
+        }
+    }
+    let image = deployment["spec"]["template"]["spec"]["containers"][0]["image"].clone();
+
+    // OLM only installs conversion webhooks of operators watching all namespaces.
+    let single_namespace = conversion_crds.is_empty();
+    let mut csv = json!({
+        "apiVersion": "operators.coreos.com/v1alpha1",
+        "kind": "ClusterServiceVersion",
+        "metadata": {
+            "name": format!("{}.v{}", package, BUNDLE_VERSION),
+            "annotations": {
+                "alm-examples": serde_json::to_string_pretty(&build_bundle_examples(schemas, examples, config)?)?,
+                "capabilities": "Basic Install",
+                "containerImage": image,
+            },
+        },
+        "spec": {
+            "displayName": config.operator_name,
+            "description": format!("{} by {}.", config.operator_name, config.operator_author),
+            "version": BUNDLE_VERSION,
+            "provider": { "name": config.operator_author },
+            "maintainers": [{ "name": config.operator_author }],
+            "installModes": [
+                { "type": "OwnNamespace", "supported": single_namespace },
+                { "type": "SingleNamespace", "supported": single_namespace },
+                { "type": "MultiNamespace", "supported": false },
+                { "type": "AllNamespaces", "supported": true },
+            ],
+            "customresourcedefinitions": { "owned": owned },
+            "install": {
+                "strategy": "deployment",
+                "spec": {
+                    "permissions": [{
+                        "serviceAccountName": service_account_name,
+                        "rules": role["rules"],
+                    }],
+                    "clusterPermissions": [{
+                        "serviceAccountName": service_account_name,
+                        "rules": cluster_role["rules"],
+                    }],
+                    "deployments": [{
+                        "name": deployment["metadata"]["name"],
+                        "spec": deployment["spec"],
+                    }],
+                },
+            },
+        },
+    });
+    if !conversion_crds.is_empty() {
+        csv["spec"]["webhookdefinitions"] = json!([{
+            "type": "ConversionWebhook",
+            "generateName": format!("{}-conversion-webhook", package),
+            "deploymentName": deployment["metadata"]["name"],
+            "containerPort": 443,
+            "targetPort": 8443,
+            "webhookPath": "/convert",
+            "sideEffects": "None",
+            "admissionReviewVersions": ["v1"],
+            "conversionCRDs": conversion_crds,
+        }]);
+    }
+
+    let content = format!(
+        "# This file is generated by kopgen. Do not edit manually. If you need to make adjustments add it to .openapi-generator-ignore file.\n---\n{}",
+        serde_yaml::to_string(&csv)?
+    );
+    write_to_file(
+        &manifests_path,
+        &format!("{}.clusterserviceversion.yaml", package),
+        content,
+    )?;
+    write_to_file(
+        &metadata_path,
+        "annotations.yaml",
+        Annotations {
+            package: package.clone(),
+            channel: BUNDLE_CHANNEL.to_string(),
+        }
+        .render()?,
+    )?;
+    write_to_file(
+        Path::new(directory),
+        "bundle.Dockerfile",
+        BundleDockerfile {
+            package,
+            channel: BUNDLE_CHANNEL.to_string(),
+        }
+        .render()?,
+    )
+}
+
+/// Builds the `alm-examples` of a bundle from the example manifests, one per resource name.
+fn build_bundle_examples(
+    schemas: &HashMap<String, Schema>,
+    examples: HashMap<String, ReferenceOr<openapiv3::Example>>,
+    config: &Config,
+) -> Result<Vec<Value>, AppError> {
+    let mut bundle_examples: Vec<Value> = vec![];
+    for (_, resources) in build_examples(schemas, examples, config)? {
+        let content = Example { resources }.render()?;
+        for document in serde_yaml::Deserializer::from_str(&content) {
+            let example = Value::deserialize(document)?;
+            let exists = bundle_examples.iter().any(|existing| {
+                existing["kind"] == example["kind"]
+                    && existing["metadata"]["name"] == example["metadata"]["name"]
+            });
+            if !example.is_null() && !exists {
+                bundle_examples.push(example);
+            }
+        }
+    }
+
+    Ok(bundle_examples)
+}
+
+/// Resources granted by RBAC, keyed by their API group.
+type RbacApiGroups = BTreeMap<String, Vec<String>>;
+
+/// Groups the resources of the namespaced and of the cluster-scoped kinds for RBAC.
+fn get_rbac_api_groups(
+    schemas: &HashMap<String, Schema>,
+    config: &Config,
+) -> Result<(RbacApiGroups, RbacApiGroups), AppError> {
+    let crd_versions = get_crd_versions(schemas, &config.api_group, &config.api_version)?;
+    let versioned_schemas: HashSet<&String> = crd_versions
+        .values()
+        .flatten()
+        .map(|(name, _)| name)
+        .collect();
+    let mut resources: Vec<String> = schemas
+        .keys()
+        .filter(|name| !versioned_schemas.contains(name))
+        .map(|name| name.to_lowercase().to_plural())
+        .collect();
+    resources.sort();
+    let cluster_scoped_resources = get_cluster_scoped_resources(schemas)?;
+    let resource_plurals = get_resource_plurals(schemas)?;
+    let resource_group_versions =
+        get_resource_group_versions(schemas, &config.api_group, &config.api_version)?;
+    let (cluster_scoped, namespaced): (Vec<&String>, Vec<&String>) = resources
+        .iter()
+        .partition(|resource| cluster_scoped_resources.contains(resource));
+
+    Ok((
+        group_rbac_resources(
+            namespaced,
+            &resource_plurals,
+            &resource_group_versions,
+            &config.api_group,
+        ),
+        group_rbac_resources(
+            cluster_scoped,
+            &resource_plurals,
+            &resource_group_versions,
+            &config.api_group,
+        ),
+    ))
+}
+
+/// Builds the CRD of every kind, keyed by the `<plural>.<group>.yaml` file it is written to.
+fn build_crds(
+    schemas: &HashMap<String, Schema>,
+    config: &Config,
+    namespace: &str,
+) -> Result<Vec<(String, Value)>, AppError> {
+    let crd_versions = get_crd_versions(schemas, &config.api_group, &config.api_version)?;
+    let versioned_schemas: HashSet<&String> = crd_versions
+        .values()
+        .flatten()
+        .map(|(name, _)| name)
+        .collect();
+
+    let mut crds = vec![];
+    for name in schemas
+        .keys()
+        .filter(|name| !versioned_schemas.contains(name))
+    {
+        let crd = build_crd(schemas, name, crd_versions.get(name), config, namespace)?;
+        let file_name = format!("{}.yaml", crd["metadata"]["name"].as_str().unwrap_or(name));
+        crds.push((file_name, crd));
+    }
+    crds.sort_by(|(a, _), (b, _)| a.cmp(b));
+    Ok(crds)
+}
+
+/// Builds the CRD of a kind, serving its additional versions through the conversion webhook
+/// in `namespace`.
+fn build_crd(
+    schemas: &HashMap<String, Schema>,
+    name: &str,
+    versions: Option<&Vec<(String, String)>>,
+    config: &Config,
+    namespace: &str,
+) -> Result<Value, AppError> {
+    let schema = &schemas[name];
+    let (group, hub_version) =
+        get_schema_group_version(schema, &config.api_group, &config.api_version)?;
+    let kind = uppercase_first_letter(name);
+    let plural = get_schema_plural(name, schema)?;
+    let storage_version = match versions {
+        Some(versions) => get_storage_version(schema, &hub_version, versions)?,
+        None => hub_version.clone(),
+    };
+
+    let mut crd_versions = vec![build_crd_version(
+        schemas,
+        name,
+        name,
+        &hub_version,
+        &storage_version,
+        &config.resource_ref,
+    )?];
+    for (version_name, version) in versions.into_iter().flatten() {
+        crd_versions.push(build_crd_version(
+            schemas,
+            version_name,
+            name,
+            version,
+            &storage_version,
+            &config.resource_ref,
+        )?);
+    }
+
+    let mut crd = json!({
+        "apiVersion": "apiextensions.k8s.io/v1",
+        "kind": "CustomResourceDefinition",
+        "metadata": { "name": format!("{}.{}", plural, group) },
+        "spec": {
+            "group": group,
+            "names": {
+                "categories": get_schema_extension_list(schema, "x-kubernetes-operator-categories")?,
+                "kind": kind,
+                "plural": plural,
+                "shortNames": get_schema_extension_list(schema, "x-kubernetes-operator-short-names")?,
+                "singular": get_schema_extension_string(schema, "x-kubernetes-operator-singular")?
+                    .unwrap_or_else(|| kind.to_lowercase()),
+            },
+            "scope": get_schema_scope(schema)?,
+            "versions": crd_versions,
+        },
+    });
+    if versions.is_some() {
+        let webhook_service_name = format!(
+            "{}-conversion-webhook",
+            filters::dashcase(&config.operator_name)?
+        );
+        crd["metadata"]["annotations"] = json!({
+            "cert-manager.io/inject-ca-from": format!("{}/{}", namespace, webhook_service_name),
+        });
+        crd["spec"]["conversion"] = json!({
+            "strategy": "Webhook",
+            "webhook": {
+                "clientConfig": {
+                    "service": {
+                        "name": webhook_service_name,
+                        "namespace": namespace,
+                        "path": "/convert",
+                        "port": 443,
+                    },
+                },
+                "conversionReviewVersions": ["v1"],
+            },
+        });
+    }
+    Ok(crd)
+}
+
+/// Builds a version of a CRD from the schema it is generated for.
+///
+/// Printer columns and the default deletion policy are shared with the hub schema of the kind.
+fn build_crd_version(
+    schemas: &HashMap<String, Schema>,
+    name: &str,
+    hub: &str,
+    version: &str,
+    storage_version: &str,
+    operator_resource_ref: &str,
+) -> Result<Value, AppError> {
+    let type_name = uppercase_first_letter(name);
+    let hub_schema = &schemas[hub];
+    let validations = get_schema_field_validations(&schemas[name])?;
+
+    let mut properties = Map::new();
+    let mut required = vec![];
+    for field in get_fields_for_type(schemas, name, operator_resource_ref)? {
+        let (mut property, optional) = get_field_schema(&field.field_type);
+        if let Some(rules) = validations.get(&field.pub_name) {
+            property["x-kubernetes-validations"] = serde_json::from_str(rules)?;
+        }
+        if !optional {
+            required.push(field.pub_name.clone());
+        }
+        properties.insert(field.pub_name, property);
+    }
+    properties.insert(
+        "deletionPolicy".to_string(),
+        json!({
+            "default": get_schema_extension_choice(
+                hub_schema,
+                "x-kubernetes-operator-deletion-policy",
+                &DELETION_POLICIES,
+                DEFAULT_DELETION_POLICY,
+            )?,
+            "description": "What happens to the remote resource when its custom resource is deleted.",
+            "enum": DELETION_POLICIES,
+            "type": "string",
+        }),
+    );
+    required.sort();
+
+    let mut spec = json!({ "properties": properties, "type": "object" });
+    if !required.is_empty() {
+        spec["required"] = json!(required);
+    }
+
+    let mut print_columns = vec![
+        json!({"name": "Ready", "type": "string", "jsonPath": ".status.conditions[?(@.type==\"Ready\")].status", "description": "Whether the remote resource exists and matches the spec"}),
+        json!({"name": "Synced", "type": "string", "jsonPath": ".status.conditions[?(@.type==\"Synced\")].status", "description": "Whether the last reconciliation succeeded"}),
+    ];
+    for print_column in get_schema_print_columns(hub_schema)? {
+        print_columns.push(serde_json::from_str(&print_column)?);
+    }
+    print_columns.push(json!({"name": "Reference ID", "type": "string", "jsonPath": format!(".status.{}", operator_resource_ref), "description": "The reference ID of the resource"}));
+    print_columns.push(json!({"name": "Age", "type": "date", "jsonPath": ".metadata.creationTimestamp", "description": "The creation time of the resource"}));
+
+    Ok(json!({
+        "additionalPrinterColumns": print_columns,
+        "name": version,
+        "schema": {
+            "openAPIV3Schema": {
+                "description": format!("Auto-generated derived type for {}Spec via `CustomResource`", type_name),
+                "properties": {
+                    "spec": spec,
+                    "status": {
+                        "nullable": true,
+                        "properties": {
+                            operator_resource_ref: { "nullable": true, "type": "string" },
+                            "conditions": {
+                                "type": "array",
+                                "x-kubernetes-list-type": "map",
+                                "x-kubernetes-list-map-keys": ["type"],
+                                "items": {
+                                    "type": "object",
+                                    "properties": {
+                                        "lastTransitionTime": { "format": "date-time", "type": "string" },
+                                        "message": { "type": "string" },
+                                        "observedGeneration": { "type": "integer", "format": "int64", "default": 0 },
+                                        "reason": { "type": "string" },
+                                        "status": { "type": "string" },
+                                        "type": { "type": "string" }
+                                    },
+                                    "required": ["lastTransitionTime", "message", "reason", "status", "type"],
+                                },
+                            },
+                            "observedGeneration": { "format": "int64", "nullable": true, "type": "integer" },
+                        },
+                        "type": "object",
+                    },
+                },
+                "required": ["spec"],
+                "title": type_name,
+                "type": "object",
+            },
+        },
+        "served": true,
+        "storage": version == storage_version,
+        "subresources": { "status": {} },
+    }))
+}
+
+/// Maps the Rust type of a field to the schema schemars generates for it.
+///
+/// Returns whether the field is optional, in which case it is nullable and not required.
+fn get_field_schema(field_type: &str) -> (Value, bool) {
+    let (base_type, optional) = match field_type
+        .strip_prefix("Option<")
+        .and_then(|field_type| field_type.strip_suffix('>'))
+    {
+        Some(base_type) => (base_type, true),
+        None => (field_type, false),
+    };
+    let mut schema = match base_type {
+        "String" => json!({ "type": "string" }),
+        "i32" => json!({ "format": "int32", "type": "integer" }),
+        "f64" => json!({ "format": "double", "type": "number" }),
+        "bool" => json!({ "type": "boolean" }),
+        _ if base_type.starts_with("Vec<") => json!({
+            "items": { "x-kubernetes-preserve-unknown-fields": true },
+            "type": "array",
+        }),
+        _ => json!({ "x-kubernetes-preserve-unknown-fields": true }),
+    };
+    if optional {
+        schema["nullable"] = json!(true);
+    }
+    (schema, optional)
+}
+
 /// Generates the main file for the Kubernetes operator.
 fn generate_main_file(
     directory: &str,
@@ -245,6 +1299,8 @@ fn generate_main_file(
     api_version: &str,
     mut controllers: Vec<String>,
     mut types: Vec<String>,
+    cluster_scoped: Vec<String>,
+    resource_crd_names: &BTreeMap<String, String>,
 ) -> Result<(), AppError> {
     let base_path = &Path::new(directory).join("src");
     let file_path = base_path.join("main.rs").to_string_lossy().to_string();
@@ -253,6 +1309,15 @@ fn generate_main_file(
     }
 
     controllers.sort();
+    let controller_crds = controllers
+        .iter()
+        .map(|controller| {
+            resource_crd_names
+                .get(controller)
+                .cloned()
+                .unwrap_or_else(|| format!("{}.{}", controller, api_group))
+        })
+        .collect();
 
     types = types
         .iter()
@@ -264,7 +1329,9 @@ fn generate_main_file(
         api_group: api_group.into(),
         api_version: api_version.into(),
         controllers,
+        controller_crds,
         types,
+        cluster_scoped,
     }
     .render()?;
 
@@ -274,6 +1341,7 @@ fn generate_main_file(
 
 /// Extracts controller attributes for a given operation.
 fn get_controller_attributes_for_operation(
+    path: &str,
     operation: &openapiv3::Operation,
     http_method: &str,
     include_tags: &[String],
@@ -297,7 +1365,7 @@ fn get_controller_attributes_for_operation(
                 // Parse the reference to get the schema name
                 let schema_name = reference
                     .split('/')
-                    .last()
+                    .next_back()
                     .unwrap_or("UnnamedSchema")
                     .to_string();
                 // Add the schema name to the HashSet
@@ -315,7 +1383,7 @@ fn get_controller_attributes_for_operation(
                 // Parse the reference to get the schema name
                 let schema_name = reference
                     .split('/')
-                    .last()
+                    .next_back()
                     .unwrap_or("UnnamedSchema")
                     .to_string();
                 response_schemas.push(schema_name);
@@ -323,10 +1391,19 @@ fn get_controller_attributes_for_operation(
         }
     }
 
+    // An optional header the remote API uses to deduplicate retried requests
+    let idempotency_key_header = operation
+        .extensions
+        .get("x-kubernetes-operator-idempotency-key-header")
+        .and_then(|value| value.as_str())
+        .map(|value| value.to_string());
+
     // Create the controller attributes
     let attributes = ControllerAttributes {
         operation_id: operation_id.to_string().to_snake_case(),
         http_method: http_method.to_string(),
+        collection: !path.contains('{'),
+        idempotency_key_header,
     };
 
     Some((tag.clone(), attributes))
@@ -338,12 +1415,12 @@ fn generate_controllers(
     directory: &str,
     schemas: &HashMap<String, Schema>,
     paths: openapiv3::Paths,
-    include_tags: Vec<String>,
-    kubernetes_operator_resource_ref: String,
+    config: &Config,
 ) -> Result<Vec<String>, AppError> {
+    let include_tags = &config.include_tags;
     let mut controllers: HashMap<String, Vec<ControllerAttributes>> = HashMap::new();
 
-    for (_path, path_item) in paths {
+    for (path, path_item) in paths {
         let item = if let ReferenceOr::Item(item) = path_item {
             item
         } else {
@@ -372,7 +1449,7 @@ fn generate_controllers(
             }
 
             if let Some((tag, controller)) =
-                get_controller_attributes_for_operation(operation, method, &include_tags)
+                get_controller_attributes_for_operation(&path, operation, method, include_tags)
             {
                 controllers.entry(tag.clone()).or_default().push(controller);
             }
@@ -385,7 +1462,7 @@ fn generate_controllers(
             schemas,
             tag.clone(),
             controller_attributes,
-            kubernetes_operator_resource_ref.clone(),
+            config,
         )?;
 
         if let Err(e) = upsert_line_to_file(
@@ -408,21 +1485,65 @@ fn generate_controller(
     schemas: &HashMap<String, Schema>,
     tag: String,
     controller_attributes: &[ControllerAttributes],
-    resource_remote_ref: String,
+    config: &Config,
 ) -> Result<(), AppError> {
     if get_ignored_files()?.contains(&format!("{}/{}.rs", directory, tag.to_lowercase())) {
         return Ok(());
     }
 
+    let resource_remote_ref = config.resource_ref.clone();
     let type_name = uppercase_first_letter(&tag.to_singular());
 
     let fields = get_fields_for_type(schemas, &type_name, &resource_remote_ref)?;
 
+    let (drift_policy, drift_ignore_fields) = match schemas.get(&type_name) {
+        Some(schema) => (
+            get_schema_extension_choice(
+                schema,
+                "x-kubernetes-operator-drift-policy",
+                &DRIFT_POLICIES,
+                DEFAULT_DRIFT_POLICY,
+            )?,
+            get_schema_extension_list(schema, "x-kubernetes-operator-drift-ignore-fields")?,
+        ),
+        None => (DEFAULT_DRIFT_POLICY.to_string(), vec![]),
+    };
+    let drift_fields = fields
+        .iter()
+        .filter(|field| !drift_ignore_fields.contains(&field.pub_name))
+        .map(|field| Field {
+            pub_name: field.pub_name.clone(),
+            field_type: field.field_type.clone(),
+        })
+        .collect();
+
+    // Used to find a remote resource a previous, partially failed create left behind
+    let list_operation_id = controller_attributes
+        .iter()
+        .find(|attributes| attributes.http_method == "get" && attributes.collection)
+        .map(|attributes| attributes.operation_id.clone());
+    let natural_key = fields
+        .iter()
+        .find(|field| field.pub_name == config.example_metadata_spec_field_ref)
+        .map(|field| field.pub_name.clone());
+    let has_create = controller_attributes
+        .iter()
+        .any(|attributes| attributes.http_method == "post");
+    if has_create && (list_operation_id.is_none() || natural_key.is_none()) {
+        warn!(
+            "{} cannot be looked up by its {} field through a list operation, a create that fails to record its {} will need the adopt-id annotation to recover",
+            type_name, config.example_metadata_spec_field_ref, resource_remote_ref
+        );
+    }
+
     let mut content: String = Controller {
         tag: tag.to_lowercase(),
+        api_group: config.api_group.clone(),
         arg_name: tag.to_lowercase().to_singular(),
         kind_struct: type_name.clone(),
         dto_fields: fields,
+        drift_fields,
+        drift_policy,
         resource_remote_ref: resource_remote_ref.clone(),
         api_url: "http://localhost:8080".to_string(),
     }
@@ -445,10 +1566,14 @@ fn generate_controller(
     .render()?;
 
     let content_action_post: String = ControllerActionPost {
+        tag: tag.to_lowercase(),
         arg_name: tag.to_lowercase().to_singular(),
         kind_struct: type_name,
         controllers: controller_attributes.iter().collect(),
         resource_remote_ref: resource_remote_ref.clone(),
+        api_group: config.api_group.clone(),
+        list_operation_id,
+        natural_key,
     }
     .render()?;
 
@@ -516,17 +1641,22 @@ fn get_fields_for_type(
 }
 
 /// Generates types based on the provided schemas and operator resource reference.
+///
+/// Schemas declaring another version of a kind are generated as that version of
+/// the kind, together with `From` conversions to and from its hub type.
 pub fn generate_types(
     directory: &str,
     schemas: &HashMap<String, Schema>,
     operator_resource_ref: &str,
+    operator_group: &str,
+    operator_version: &str,
 ) -> Result<(), AppError> {
     for name in schemas.keys() {
         generate_type(
             schemas,
             name,
-            "example.com",
-            "v1",
+            operator_group,
+            operator_version,
             operator_resource_ref,
             directory,
         )?;
@@ -558,18 +1688,91 @@ fn generate_type(
         }
     };
 
-    let tag_name = name.to_string().to_lowercase().to_plural();
+    // Another version of a kind shares the CRD metadata of its hub schema.
+    let version_of = match schemas.get(name) {
+        Some(schema) => get_schema_version_of(schema)?,
+        None => None,
+    };
+    let crd_schema_name = match &version_of {
+        Some((hub, _)) => hub.as_str(),
+        None => name,
+    };
+
+    let (group_name, hub_version) = match schemas.get(crd_schema_name) {
+        Some(schema) => get_schema_group_version(schema, operator_group, operator_version)?,
+        None => (operator_group.to_string(), operator_version.to_string()),
+    };
+    let api_version = match &version_of {
+        Some((_, version)) => version.clone(),
+        None => hub_version,
+    };
+
+    let deletion_policy = match schemas.get(crd_schema_name) {
+        Some(schema) => get_schema_extension_choice(
+            schema,
+            "x-kubernetes-operator-deletion-policy",
+            &DELETION_POLICIES,
+            DEFAULT_DELETION_POLICY,
+        )?,
+        None => DEFAULT_DELETION_POLICY.to_string(),
+    };
+
+    let cluster_scoped = match schemas.get(crd_schema_name) {
+        Some(schema) => get_schema_scope(schema)? == "Cluster",
+        None => false,
+    };
+
+    let (tag_name, singular, short_names, categories, print_columns) =
+        match schemas.get(crd_schema_name) {
+            Some(schema) => (
+                get_schema_plural(crd_schema_name, schema)?,
+                get_schema_extension_string(schema, "x-kubernetes-operator-singular")?,
+                get_schema_extension_list(schema, "x-kubernetes-operator-short-names")?,
+                get_schema_extension_list(schema, "x-kubernetes-operator-categories")?,
+                get_schema_print_columns(schema)?,
+            ),
+            None => (
+                crd_schema_name.to_lowercase().to_plural(),
+                None,
+                vec![],
+                vec![],
+                vec![],
+            ),
+        };
+    let validations = match schemas.get(name) {
+        Some(schema) => get_schema_field_validations(schema)?,
+        None => BTreeMap::new(),
+    };
+    let conversion = match &version_of {
+        Some((hub, _)) => Some(get_type_conversion(
+            schemas,
+            hub,
+            &fields,
+            operator_resource_ref,
+        )?),
+        None => None,
+    };
+
     let arg_name = name.to_lowercase();
     let type_name = uppercase_first_letter(name);
     let arg_name_clone = arg_name.clone();
 
     let content: String = TypeTemplate {
         tag_name,
+        kind: uppercase_first_letter(crd_schema_name),
+        conversion,
+        singular,
+        short_names,
+        categories,
+        print_columns,
+        validations,
         type_name,
-        api_version: operator_version.to_string(),
-        group_name: operator_group.to_string(),
+        api_version,
+        group_name,
         fields,
         reference_id: operator_resource_ref.to_string(),
+        deletion_policy,
+        cluster_scoped,
     }
     .render()?;
 
@@ -579,6 +1782,594 @@ fn generate_type(
     format_file(base_path.join(file_name).to_str().unwrap())
 }
 
+/// Builds the `From` conversions between a version of a kind and its hub type.
+///
+/// Fields with the same name and type are copied. The others are listed with
+/// their defaults, the required ones marked to be mapped by hand.
+fn get_type_conversion(
+    schemas: &HashMap<String, Schema>,
+    hub: &str,
+    fields: &[Field],
+    operator_resource_ref: &str,
+) -> Result<TypeConversion, AppError> {
+    let hub_fields = get_fields_for_type(schemas, hub, operator_resource_ref)?;
+    let is_common = |field: &Field, others: &[Field]| {
+        others
+            .iter()
+            .any(|other| other.pub_name == field.pub_name && other.field_type == field.field_type)
+    };
+    let one_sided_fields = |fields: &[Field], others: &[Field]| -> Vec<Field> {
+        fields
+            .iter()
+            .filter(|field| !is_common(field, others))
+            .map(|field| Field {
+                pub_name: field.pub_name.clone(),
+                field_type: field.field_type.clone(),
+            })
+            .collect()
+    };
+
+    Ok(TypeConversion {
+        hub: uppercase_first_letter(hub),
+        hub_module: hub.to_lowercase(),
+        common_fields: fields
+            .iter()
+            .filter(|field| is_common(field, &hub_fields))
+            .map(|field| field.pub_name.clone())
+            .collect(),
+        version_only_fields: one_sided_fields(fields, &hub_fields),
+        hub_only_fields: one_sided_fields(&hub_fields, fields),
+    })
+}
+
+/// Retrieves the hub schema and version of a schema declaring another version of a kind.
+fn get_schema_version_of(schema: &Schema) -> Result<Option<(String, String)>, AppError> {
+    let version_of = get_schema_extension_string(schema, "x-kubernetes-operator-version-of")?;
+    let version = get_schema_extension_string(schema, "x-kubernetes-operator-version")?;
+    match (version_of, version) {
+        (None, None) => Ok(None),
+        (Some(version_of), Some(version)) => Ok(Some((version_of, version))),
+        _ => Err(AppError::ConfigError(
+            "'x-kubernetes-operator-version-of' and 'x-kubernetes-operator-version' must be set together."
+                .to_string(),
+        )),
+    }
+}
+
+/// Collects the schema name and version of every additional version, keyed by hub schema.
+fn get_crd_versions(
+    schemas: &HashMap<String, Schema>,
+    operator_group: &str,
+    operator_version: &str,
+) -> Result<BTreeMap<String, Vec<(String, String)>>, AppError> {
+    let mut crd_versions: BTreeMap<String, Vec<(String, String)>> = BTreeMap::new();
+    for (name, schema) in schemas {
+        let Some((hub, version)) = get_schema_version_of(schema)? else {
+            continue;
+        };
+        let hub_version = match schemas.get(&hub) {
+            Some(hub_schema) if get_schema_version_of(hub_schema)?.is_none() => {
+                get_schema_group_version(hub_schema, operator_group, operator_version)?.1
+            }
+            _ => {
+                return Err(AppError::ConfigError(format!(
+                    "Schema {} is a version of {}, which is not a kind of its own.",
+                    name, hub
+                )))
+            }
+        };
+        // The group of a version is the group of its kind.
+        for key in [
+            "x-kubernetes-operator-api-group",
+            "x-kubernetes-operator-api-version",
+        ] {
+            if schema.schema_data.extensions.contains_key(key) {
+                return Err(AppError::ConfigError(format!(
+                    "Schema {} is a version of {} and cannot set '{}'.",
+                    name, hub, key
+                )));
+            }
+        }
+
+        let versions = crd_versions.entry(hub.clone()).or_default();
+        if version == hub_version || versions.iter().any(|(_, other)| other == &version) {
+            return Err(AppError::ConfigError(format!(
+                "Version {} of {} is declared more than once.",
+                version, hub
+            )));
+        }
+        versions.push((name.clone(), version));
+    }
+
+    for versions in crd_versions.values_mut() {
+        versions.sort();
+    }
+    Ok(crd_versions)
+}
+
+/// Retrieves the version a kind is stored in, defaulting to the operator API version.
+fn get_storage_version(
+    schema: &Schema,
+    operator_version: &str,
+    versions: &[(String, String)],
+) -> Result<String, AppError> {
+    let storage_version =
+        get_schema_extension_string(schema, "x-kubernetes-operator-storage-version")?
+            .unwrap_or_else(|| operator_version.to_string());
+    if storage_version != operator_version
+        && !versions
+            .iter()
+            .any(|(_, version)| version == &storage_version)
+    {
+        return Err(AppError::ConfigError(format!(
+            "Storage version {} is not a version of the kind.",
+            storage_version
+        )));
+    }
+    Ok(storage_version)
+}
+
+/// Retrieves whether the operator installs its CRDs on startup, which it does by default.
+fn get_install_crds(openapi: &OpenAPI) -> Result<bool, AppError> {
+    match openapi
+        .info
+        .extensions
+        .get("x-kubernetes-operator-install-crds")
+    {
+        None => Ok(true),
+        Some(Value::Bool(install_crds)) => Ok(*install_crds),
+        Some(_) => Err(AppError::ConfigError(
+            "'x-kubernetes-operator-install-crds' must be a boolean.".to_string(),
+        )),
+    }
+}
+
+/// Settings of the operator Deployment, read from the extensions in `info`.
+pub struct DeploymentSettings {
+    resources: Value,
+    pod_security_context: Value,
+    security_context: Value,
+    affinity: Option<Value>,
+    topology_spread_constraints: Option<Value>,
+    pod_disruption_budget: Option<Value>,
+    network_policy: Option<NetworkPolicySettings>,
+}
+
+/// Destinations the NetworkPolicy allows egress to, besides DNS.
+pub struct NetworkPolicySettings {
+    api_server_cidrs: Vec<String>,
+    remote_host: String,
+    remote_port: u16,
+    remote_cidrs: Vec<String>,
+}
+
+/// Retrieves the settings of the operator Deployment.
+///
+/// The resources and security contexts default to secure settings matching the
+/// distroless `nonroot` image of the operator. The NetworkPolicy restricts egress
+/// to the Kubernetes API server and the first server of the OpenAPI spec, which
+/// both have to be given as CIDRs unless the server host is an IP address.
+pub fn get_deployment_settings(openapi: &OpenAPI) -> Result<DeploymentSettings, AppError> {
+    let network_policy = match openapi
+        .info
+        .extensions
+        .get("x-kubernetes-operator-network-policy")
+    {
+        None | Some(Value::Bool(false)) => None,
+        Some(Value::Bool(true)) => Some(get_network_policy_settings(openapi)?),
+        Some(_) => {
+            return Err(AppError::ConfigError(
+                "'x-kubernetes-operator-network-policy' must be a boolean.".to_string(),
+            ))
+        }
+    };
+
+    Ok(DeploymentSettings {
+        resources: get_info_extension_object(openapi, "x-kubernetes-operator-resources")?
+            .unwrap_or_else(|| {
+                json!({
+                    "requests": {"cpu": "100m", "memory": "128Mi"},
+                    "limits": {"cpu": "500m", "memory": "512Mi"},
+                })
+            }),
+        pod_security_context: get_info_extension_object(
+            openapi,
+            "x-kubernetes-operator-pod-security-context",
+        )?
+        .unwrap_or_else(|| {
+            json!({
+                "runAsNonRoot": true,
+                "runAsUser": 65532,
+                "runAsGroup": 65532,
+                "seccompProfile": {"type": "RuntimeDefault"},
+            })
+        }),
+        security_context: get_info_extension_object(
+            openapi,
+            "x-kubernetes-operator-security-context",
+        )?
+        .unwrap_or_else(|| {
+            json!({
+                "allowPrivilegeEscalation": false,
+                "readOnlyRootFilesystem": true,
+                "capabilities": {"drop": ["ALL"]},
+            })
+        }),
+        affinity: get_info_extension_object(openapi, "x-kubernetes-operator-affinity")?,
+        topology_spread_constraints: match openapi
+            .info
+            .extensions
+            .get("x-kubernetes-operator-topology-spread-constraints")
+        {
+            None => None,
+            Some(constraints @ Value::Array(_)) => Some(constraints.clone()),
+            Some(_) => {
+                return Err(AppError::ConfigError(
+                    "'x-kubernetes-operator-topology-spread-constraints' must be a list."
+                        .to_string(),
+                ))
+            }
+        },
+        pod_disruption_budget: get_info_extension_object(
+            openapi,
+            "x-kubernetes-operator-pod-disruption-budget",
+        )?,
+        network_policy,
+    })
+}
+
+/// Retrieves the egress destinations of the NetworkPolicy.
+///
+/// NetworkPolicies cannot match host names, so a remote API addressed by host name
+/// requires `x-kubernetes-operator-network-policy-remote-cidrs`.
+fn get_network_policy_settings(openapi: &OpenAPI) -> Result<NetworkPolicySettings, AppError> {
+    let (remote_host, remote_port) = openapi
+        .servers
+        .first()
+        .and_then(|server| parse_server_url(&server.url))
+        .ok_or_else(|| {
+            AppError::ConfigError(
+                "'x-kubernetes-operator-network-policy' requires an absolute URL as the first server.".to_string(),
+            )
+        })?;
+    let api_server_cidrs =
+        get_info_extension_cidrs(openapi, "x-kubernetes-operator-network-policy-api-server-cidrs")?
+            .ok_or_else(|| {
+                AppError::ConfigError(
+                    "'x-kubernetes-operator-network-policy' requires 'x-kubernetes-operator-network-policy-api-server-cidrs'.".to_string(),
+                )
+            })?;
+    let remote_cidrs =
+        match get_info_extension_cidrs(openapi, "x-kubernetes-operator-network-policy-remote-cidrs")? {
+            Some(cidrs) => cidrs,
+            None => match remote_host.parse::<IpAddr>() {
+                Ok(IpAddr::V4(ip)) => vec![format!("{}/32", ip)],
+                Ok(IpAddr::V6(ip)) => vec![format!("{}/128", ip)],
+                Err(_) => {
+                    return Err(AppError::ConfigError(format!(
+                        "'x-kubernetes-operator-network-policy' requires 'x-kubernetes-operator-network-policy-remote-cidrs', as NetworkPolicies cannot match the host name {}.",
+                        remote_host
+                    )))
+                }
+            },
+        };
+
+    Ok(NetworkPolicySettings {
+        api_server_cidrs,
+        remote_host,
+        remote_port,
+        remote_cidrs,
+    })
+}
+
+/// Retrieves an extension in `info` which must be a non-empty list of CIDRs when set.
+fn get_info_extension_cidrs(
+    openapi: &OpenAPI,
+    name: &str,
+) -> Result<Option<Vec<String>>, AppError> {
+    let Some(value) = openapi.info.extensions.get(name) else {
+        return Ok(None);
+    };
+    let cidrs: Vec<String> = value
+        .as_array()
+        .filter(|cidrs| !cidrs.is_empty())
+        .and_then(|cidrs| {
+            cidrs
+                .iter()
+                .map(|cidr| cidr.as_str().filter(|cidr| is_cidr(cidr)).map(String::from))
+                .collect()
+        })
+        .ok_or_else(|| {
+            AppError::ConfigError(format!(
+                "'{}' must be a non-empty list of CIDRs, like 10.0.0.1/32.",
+                name
+            ))
+        })?;
+    Ok(Some(cidrs))
+}
+
+/// Checks that a value is an IP address with a prefix length, like `10.0.0.0/8`.
+fn is_cidr(value: &str) -> bool {
+    let Some((ip, prefix)) = value.split_once('/') else {
+        return false;
+    };
+    match (ip.parse::<IpAddr>(), prefix.parse::<u8>()) {
+        (Ok(IpAddr::V4(_)), Ok(prefix)) => prefix <= 32,
+        (Ok(IpAddr::V6(_)), Ok(prefix)) => prefix <= 128,
+        _ => false,
+    }
+}
+
+/// Retrieves an extension in `info` which must be an object when set.
+fn get_info_extension_object(openapi: &OpenAPI, name: &str) -> Result<Option<Value>, AppError> {
+    match openapi.info.extensions.get(name) {
+        None => Ok(None),
+        Some(value @ Value::Object(_)) => Ok(Some(value.clone())),
+        Some(_) => Err(AppError::ConfigError(format!(
+            "'{}' must be an object.",
+            name
+        ))),
+    }
+}
+
+/// Parses the host and port of an absolute server URL, defaulting the port by scheme.
+fn parse_server_url(url: &str) -> Option<(String, u16)> {
+    let (scheme, rest) = url.split_once("://")?;
+    let authority = rest.split(['/', '?', '#']).next()?;
+    let authority = authority.rsplit('@').next()?;
+    let (host, port) = match authority.rsplit_once(':') {
+        Some((host, port)) if !port.contains(']') => (host, Some(port.parse().ok()?)),
+        _ => (authority, None),
+    };
+    let port = match (port, scheme) {
+        (Some(port), _) => port,
+        (None, "http") => 80,
+        (None, "https") => 443,
+        (None, _) => return None,
+    };
+    if host.is_empty() {
+        return None;
+    }
+    Some((host.trim_matches(['[', ']']).to_string(), port))
+}
+
+/// Retrieves the scope of the CRD generated for a schema, either `Namespaced` or `Cluster`.
+fn get_schema_scope(schema: &Schema) -> Result<String, AppError> {
+    get_schema_extension_choice(
+        schema,
+        "x-kubernetes-operator-scope",
+        &SCOPES,
+        DEFAULT_SCOPE,
+    )
+}
+
+/// Returns the plural names of the schemas generated as cluster-scoped CRDs.
+fn get_cluster_scoped_resources(
+    schemas: &HashMap<String, Schema>,
+) -> Result<Vec<String>, AppError> {
+    let mut resources = vec![];
+    for (name, schema) in schemas {
+        if get_schema_scope(schema)? == "Cluster" {
+            resources.push(name.to_lowercase().to_plural());
+        }
+    }
+    resources.sort();
+    Ok(resources)
+}
+
+/// Retrieves the plural of the CRD generated for a schema, defaulting to the inflected schema name.
+fn get_schema_plural(name: &str, schema: &Schema) -> Result<String, AppError> {
+    Ok(
+        get_schema_extension_string(schema, "x-kubernetes-operator-plural")?
+            .unwrap_or_else(|| name.to_lowercase().to_plural()),
+    )
+}
+
+/// Maps the inflected plural of every schema to the plural of its CRD.
+fn get_resource_plurals(
+    schemas: &HashMap<String, Schema>,
+) -> Result<BTreeMap<String, String>, AppError> {
+    let mut plurals = BTreeMap::new();
+    for (name, schema) in schemas {
+        plurals.insert(
+            name.to_lowercase().to_plural(),
+            get_schema_plural(name, schema)?,
+        );
+    }
+    Ok(plurals)
+}
+
+/// Retrieves the API group and version of the CRD generated for a schema, defaulting to the operator's.
+fn get_schema_group_version(
+    schema: &Schema,
+    operator_group: &str,
+    operator_version: &str,
+) -> Result<(String, String), AppError> {
+    let group = get_schema_extension_string(schema, "x-kubernetes-operator-api-group")?
+        .unwrap_or_else(|| operator_group.to_string());
+    let version = get_schema_extension_string(schema, "x-kubernetes-operator-api-version")?
+        .unwrap_or_else(|| operator_version.to_string());
+    Ok((group, version))
+}
+
+/// Maps the inflected plural of every schema to the API group and version of its CRD.
+fn get_resource_group_versions(
+    schemas: &HashMap<String, Schema>,
+    operator_group: &str,
+    operator_version: &str,
+) -> Result<BTreeMap<String, (String, String)>, AppError> {
+    let mut group_versions = BTreeMap::new();
+    for (name, schema) in schemas {
+        group_versions.insert(
+            name.to_lowercase().to_plural(),
+            get_schema_group_version(schema, operator_group, operator_version)?,
+        );
+    }
+    Ok(group_versions)
+}
+
+/// Maps the inflected plural of every schema to the name of its CRD.
+fn get_resource_crd_names(
+    resource_plurals: &BTreeMap<String, String>,
+    resource_group_versions: &BTreeMap<String, (String, String)>,
+) -> BTreeMap<String, String> {
+    resource_plurals
+        .iter()
+        .filter_map(|(resource, plural)| {
+            let (group, _) = resource_group_versions.get(resource)?;
+            Some((resource.clone(), format!("{}.{}", plural, group)))
+        })
+        .collect()
+}
+
+/// Retrieves the additional printer columns of a schema as JSON column definitions.
+///
+/// Each column needs a `name` and a `jsonPath`, the `type` defaults to `string`.
+fn get_schema_print_columns(schema: &Schema) -> Result<Vec<String>, AppError> {
+    let key = "x-kubernetes-operator-print-columns";
+    let columns = match schema.schema_data.extensions.get(key) {
+        None => return Ok(vec![]),
+        Some(Value::Array(columns)) => columns,
+        Some(_) => {
+            return Err(AppError::ConfigError(format!(
+                "'{}' must be a list of columns.",
+                key
+            )))
+        }
+    };
+
+    columns
+        .iter()
+        .map(|column| {
+            let attribute = |name: &str| column.get(name).and_then(Value::as_str);
+            let (Some(name), Some(json_path)) = (attribute("name"), attribute("jsonPath")) else {
+                return Err(AppError::ConfigError(format!(
+                    "A column in '{}' is missing its name or jsonPath.",
+                    key
+                )));
+            };
+            let mut definition = serde_json::json!({
+                "name": name,
+                "type": attribute("type").unwrap_or("string"),
+                "jsonPath": json_path,
+            });
+            if let Some(description) = attribute("description") {
+                definition["description"] = Value::from(description);
+            }
+            if let Some(priority) = column.get("priority").and_then(Value::as_i64) {
+                definition["priority"] = Value::from(priority);
+            }
+            Ok(definition.to_string())
+        })
+        .collect()
+}
+
+/// Retrieves an optional string schema extension.
+fn get_schema_extension_string(schema: &Schema, key: &str) -> Result<Option<String>, AppError> {
+    match schema.schema_data.extensions.get(key) {
+        None => Ok(None),
+        Some(Value::String(value)) => Ok(Some(value.clone())),
+        Some(_) => Err(AppError::ConfigError(format!(
+            "'{}' must be a string.",
+            key
+        ))),
+    }
+}
+
+/// Retrieves a string schema extension restricted to a set of allowed values.
+fn get_schema_extension_choice(
+    schema: &Schema,
+    key: &str,
+    allowed: &[&str],
+    default: &str,
+) -> Result<String, AppError> {
+    match schema.schema_data.extensions.get(key) {
+        None => Ok(default.to_string()),
+        Some(Value::String(value)) if allowed.contains(&value.as_str()) => Ok(value.clone()),
+        Some(value) => Err(AppError::ConfigError(format!(
+            "Invalid {} {}, expected one of: {}",
+            key,
+            value,
+            allowed.join(", ")
+        ))),
+    }
+}
+
+/// Collects the CEL validation rules of every property of a schema as JSON lists.
+///
+/// `x-kubernetes-operator-immutable: true` adds a `self == oldSelf` rule and
+/// `x-kubernetes-operator-validation` adds one or more custom rules.
+fn get_schema_field_validations(schema: &Schema) -> Result<BTreeMap<String, String>, AppError> {
+    let object = match &schema.schema_kind {
+        SchemaKind::Type(Type::Object(object)) => object,
+        _ => return Ok(BTreeMap::new()),
+    };
+
+    let mut validations = BTreeMap::new();
+    for (field_name, field_schema) in &object.properties {
+        let ReferenceOr::Item(item) = field_schema else {
+            continue;
+        };
+
+        let mut rules = vec![];
+        match item
+            .schema_data
+            .extensions
+            .get("x-kubernetes-operator-immutable")
+        {
+            None | Some(Value::Bool(false)) => {}
+            Some(Value::Bool(true)) => rules.push(json!({
+                "rule": "self == oldSelf",
+                "message": format!("{} is immutable", field_name),
+            })),
+            Some(_) => {
+                return Err(AppError::ConfigError(format!(
+                    "'x-kubernetes-operator-immutable' of {} must be a boolean.",
+                    field_name
+                )))
+            }
+        }
+        let custom_rules = match item
+            .schema_data
+            .extensions
+            .get("x-kubernetes-operator-validation")
+        {
+            Some(Value::String(rule)) => vec![rule.clone()],
+            _ => get_schema_extension_list(item, "x-kubernetes-operator-validation")?,
+        };
+        for rule in custom_rules {
+            rules.push(json!({ "rule": rule }));
+        }
+
+        if !rules.is_empty() {
+            validations.insert(field_name.clone(), Value::Array(rules).to_string());
+        }
+    }
+    Ok(validations)
+}
+
+/// Retrieves a string array schema extension.
+fn get_schema_extension_list(schema: &Schema, key: &str) -> Result<Vec<String>, AppError> {
+    match schema.schema_data.extensions.get(key) {
+        None => Ok(vec![]),
+        Some(Value::Array(values)) => values
+            .iter()
+            .map(|value| {
+                value
+                    .as_str()
+                    .map(|value| value.to_string())
+                    .ok_or_else(|| {
+                        AppError::ConfigError(format!("An element in '{}' is not a string.", key))
+                    })
+            })
+            .collect(),
+        Some(_) => Err(AppError::ConfigError(format!(
+            "The '{}' extension is not an array.",
+            key
+        ))),
+    }
+}
+
 /// Adds a type to the module file.
 fn add_type_to_modfile(type_name: &str, directory: &str) -> Result<(), AppError> {
     let mod_file_path = Path::new(directory).join("mod.rs");
@@ -611,7 +2402,11 @@ fn add_controller_to_modfile(directory: &str, controller_name: &str) -> Result<(
 }
 
 /// Generates the CRD generator main file based on the provided resources.
-fn generate_crdgen_file(directory: &str, resources: Vec<String>) -> Result<(), AppError> {
+fn generate_crdgen_file(
+    directory: &str,
+    resources: Vec<String>,
+    namespace: &str,
+) -> Result<(), AppError> {
     let base_path: &Path = &Path::new(directory).join("src");
     let file_name = "main.rs".to_string();
 
@@ -625,53 +2420,313 @@ fn generate_crdgen_file(directory: &str, resources: Vec<String>) -> Result<(), A
         })
         .collect();
 
-    let template = CrdGenTemplate { resources };
+    let template = CrdGenTemplate {
+        namespace: namespace.to_string(),
+        resources,
+    };
     let content = template.render()?;
     write_to_file(base_path, &file_name, content)?;
     format_file(base_path.join(file_name).to_str().unwrap())
 }
 
 /// Generates example manifests based on the provided examples.
+///
+/// Kinds without an example get one synthesized from their schema.
 fn generate_examples(
     directory: &str,
-    kubernetes_operator_metadata_spec_field_name: &str,
+    schemas: &HashMap<String, Schema>,
     examples: std::collections::HashMap<String, ReferenceOr<openapiv3::Example>>,
-    operator_group: &str,
-    operator_version: &str,
-    operator_resource_ref: &str,
+    config: &Config,
 ) -> Result<(), AppError> {
-    let examples_map: std::collections::HashMap<String, openapiv3::Example> = examples
+    for (name, resources) in build_examples(schemas, examples, config)? {
+        write_example_manifest(directory, &name, resources)?;
+    }
+
+    Ok(())
+}
+
+/// Builds the resources of every example, keyed by the name of the example.
+fn build_examples(
+    schemas: &HashMap<String, Schema>,
+    examples: std::collections::HashMap<String, ReferenceOr<openapiv3::Example>>,
+    config: &Config,
+) -> Result<BTreeMap<String, Vec<Resource>>, AppError> {
+    let resource_group_versions =
+        get_resource_group_versions(schemas, &config.api_group, &config.api_version)?;
+    let mut examples_map: std::collections::HashMap<String, openapiv3::Example> = examples
         .into_iter()
         .filter_map(|(k, v)| match v {
             ReferenceOr::Item(item) => Some((k, item)),
             ReferenceOr::Reference { .. } => None,
         })
         .collect();
+    let schema_examples = build_schema_examples(schemas, &examples_map, config)?;
+    examples_map.extend(schema_examples);
+
+    let mut manifests = BTreeMap::new();
     for (name, example) in &examples_map {
-        generate_manifest_from_example(
-            directory,
-            kubernetes_operator_metadata_spec_field_name,
+        let (group, version) = resource_group_versions
+            .get(&name.to_lowercase().to_plural())
+            .map_or(
+                (config.api_group.as_str(), config.api_version.as_str()),
+                |(group, version)| (group.as_str(), version.as_str()),
+            );
+        let resources = build_resources_from_example(
+            &config.example_metadata_spec_field_ref,
             name,
             example,
-            operator_group,
-            operator_version,
-            operator_resource_ref,
-        )?;
+            group,
+            version,
+            &config.resource_ref,
+        );
+        if !resources.is_empty() {
+            manifests.insert(name.clone(), resources);
+        }
     }
 
-    Ok(())
+    Ok(manifests)
 }
 
-/// Generates a manifest from an example.
-fn generate_manifest_from_example(
-    directory: &str,
+/// Synthesizes an example of every kind without one in `examples` from its schema.
+///
+/// Values are taken from the `example`, `default` or first `enum` value of the
+/// schema and its properties, falling back to placeholders valid for their type.
+fn build_schema_examples(
+    schemas: &HashMap<String, Schema>,
+    examples: &HashMap<String, openapiv3::Example>,
+    config: &Config,
+) -> Result<HashMap<String, openapiv3::Example>, AppError> {
+    let crd_versions = get_crd_versions(schemas, &config.api_group, &config.api_version)?;
+    let versioned_schemas: HashSet<&String> = crd_versions
+        .values()
+        .flatten()
+        .map(|(name, _)| name)
+        .collect();
+
+    let mut schema_examples = HashMap::new();
+    for (name, schema) in schemas {
+        let kind = uppercase_first_letter(name).to_singular();
+        if versioned_schemas.contains(name)
+            || examples
+                .keys()
+                .any(|example| uppercase_first_letter(example).to_singular() == kind)
+        {
+            continue;
+        }
+        let SchemaKind::Type(Type::Object(object)) = &schema.schema_kind else {
+            continue;
+        };
+
+        let mut example = match &schema.schema_data.example {
+            Some(Value::Object(example)) => example.clone(),
+            _ => Map::new(),
+        };
+        for field in get_fields_for_type(schemas, name, &config.resource_ref)? {
+            if example.contains_key(&field.pub_name) {
+                continue;
+            }
+            let Some(ReferenceOr::Item(property)) = object.properties.get(&field.pub_name) else {
+                continue;
+            };
+            // The metadata name of the example is taken from this field.
+            let placeholder = if field.pub_name == config.example_metadata_spec_field_ref {
+                kind.to_kebab_case()
+            } else {
+                field.pub_name.to_kebab_case()
+            };
+            let required = object.required.contains(&field.pub_name);
+            if let Some(value) =
+                get_schema_example_value(schemas, property, &placeholder, required, 0)
+            {
+                example.insert(field.pub_name.clone(), value);
+            }
+        }
+
+        info!("Synthesizing an example of {} from its schema.", kind);
+        schema_examples.insert(
+            name.clone(),
+            openapiv3::Example {
+                value: Some(Value::Object(example)),
+                ..Default::default()
+            },
+        );
+    }
+
+    Ok(schema_examples)
+}
+
+/// Builds an example value of a schema from its `example`, `default` or first `enum`
+/// value, falling back to a placeholder valid for its type and bounds.
+///
+/// Strings constrained by a `pattern` cannot be synthesized, so they are left out unless
+/// they are `required`. The generated CRDs don't enforce the pattern, so required ones keep
+/// their placeholder.
+fn get_schema_example_value(
+    schemas: &HashMap<String, Schema>,
+    schema: &Schema,
+    placeholder: &str,
+    required: bool,
+    depth: usize,
+) -> Option<Value> {
+    if let Some(example) = &schema.schema_data.example {
+        return Some(example.clone());
+    }
+    if let Some(default) = &schema.schema_data.default {
+        return Some(default.clone());
+    }
+
+    let value = match &schema.schema_kind {
+        SchemaKind::Type(Type::String(string)) => {
+            if let Some(value) = string.enumeration.iter().flatten().next() {
+                return Some(json!(value));
+            }
+            if let Some(pattern) = &string.pattern {
+                if !required {
+                    warn!(
+                        "Leaving {} out of the synthesized example, as it has to match the pattern {}. Add an example to its schema.",
+                        placeholder, pattern
+                    );
+                    return None;
+                }
+                warn!(
+                    "Synthesizing a placeholder for the required {}, which does not match the pattern {}. Add an example to its schema.",
+                    placeholder, pattern
+                );
+            }
+            let mut value = match &string.format {
+                VariantOrUnknownOrEmpty::Item(StringFormat::Date) => "2024-01-01".to_string(),
+                VariantOrUnknownOrEmpty::Item(StringFormat::DateTime) => {
+                    "2024-01-01T00:00:00Z".to_string()
+                }
+                VariantOrUnknownOrEmpty::Unknown(format) => match format.as_str() {
+                    "uuid" => "00000000-0000-0000-0000-000000000000".to_string(),
+                    "email" => "user@example.com".to_string(),
+                    "uri" | "url" => "https://example.com".to_string(),
+                    "hostname" => "example.com".to_string(),
+                    "ipv4" => "192.0.2.1".to_string(),
+                    _ => placeholder.to_string(),
+                },
+                _ => placeholder.to_string(),
+            };
+            if let Some(min_length) = string.min_length {
+                while value.chars().count() < min_length {
+                    value.push('x');
+                }
+            }
+            if let Some(max_length) = string.max_length {
+                value = value.chars().take(max_length).collect();
+            }
+            json!(value)
+        }
+        SchemaKind::Type(Type::Integer(integer)) => {
+            if let Some(value) = integer.enumeration.iter().flatten().next() {
+                return Some(json!(value));
+            }
+            let minimum = integer
+                .minimum
+                .map(|minimum| minimum + i64::from(integer.exclusive_minimum));
+            let maximum = integer
+                .maximum
+                .map(|maximum| maximum - i64::from(integer.exclusive_maximum));
+            json!(minimum
+                .unwrap_or(0)
+                .min(maximum.unwrap_or(i64::MAX))
+                .max(minimum.unwrap_or(i64::MIN)))
+        }
+        SchemaKind::Type(Type::Number(number)) => {
+            if let Some(value) = number.enumeration.iter().flatten().next() {
+                return Some(json!(value));
+            }
+            let value = number
+                .minimum
+                .unwrap_or(0.0)
+                .min(number.maximum.unwrap_or(f64::MAX));
+            let on_minimum = number.exclusive_minimum && number.minimum == Some(value);
+            let on_maximum = number.exclusive_maximum && number.maximum == Some(value);
+            if !on_minimum && !on_maximum {
+                json!(value)
+            } else {
+                // Exclusive bounds move the value inside the range.
+                match (number.minimum, number.maximum) {
+                    (Some(minimum), Some(maximum)) => json!((minimum + maximum) / 2.0),
+                    (Some(minimum), None) => json!(minimum + 1.0),
+                    (None, Some(maximum)) => json!(maximum - 1.0),
+                    (None, None) => json!(value),
+                }
+            }
+        }
+        SchemaKind::Type(Type::Boolean(boolean)) => {
+            json!(boolean
+                .enumeration
+                .iter()
+                .flatten()
+                .next()
+                .unwrap_or(&false))
+        }
+        SchemaKind::Type(Type::Array(array)) => {
+            let item = match &array.items {
+                // References are resolved a few levels deep, cyclic schemas end in empty objects.
+                _ if depth >= 3 => json!({}),
+                Some(ReferenceOr::Item(item)) => get_schema_example_value(
+                    schemas,
+                    item,
+                    &placeholder.to_singular(),
+                    required,
+                    depth + 1,
+                )?,
+                Some(ReferenceOr::Reference { reference }) => match reference
+                    .rsplit('/')
+                    .next()
+                    .and_then(|name| schemas.get(name))
+                {
+                    Some(item) => {
+                        get_schema_example_value(schemas, item, placeholder, required, depth + 1)?
+                    }
+                    None => json!({}),
+                },
+                None => json!(placeholder.to_singular()),
+            };
+            Value::Array(vec![item; array.min_items.unwrap_or(1).max(1)])
+        }
+        SchemaKind::Type(Type::Object(object)) if depth < 3 => Value::Object(
+            object
+                .properties
+                .iter()
+                .filter_map(|(name, property)| {
+                    let property = match property {
+                        ReferenceOr::Item(property) => property.as_ref(),
+                        ReferenceOr::Reference { reference } => {
+                            schemas.get(reference.rsplit('/').next()?)?
+                        }
+                    };
+                    if property.schema_data.read_only {
+                        return None;
+                    }
+                    let value = get_schema_example_value(
+                        schemas,
+                        property,
+                        &name.to_kebab_case(),
+                        object.required.contains(name),
+                        depth + 1,
+                    )?;
+                    Some((name.clone(), value))
+                })
+                .collect(),
+        ),
+        _ => json!({}),
+    };
+    Some(value)
+}
+
+/// Builds the resources of an example.
+fn build_resources_from_example(
     kubernetes_operator_metadata_spec_field_name: &str,
     name: &str,
     example: &openapiv3::Example,
     operator_group: &str,
     operator_version: &str,
     operator_resource_ref: &str,
-) -> Result<(), AppError> {
+) -> Vec<Resource> {
     let mut resources = Vec::new();
 
     match &example.value {
@@ -706,11 +2761,7 @@ fn generate_manifest_from_example(
         _ => (),
     }
 
-    if !resources.is_empty() {
-        write_example_manifest(directory, name, resources)?;
-    }
-
-    Ok(())
+    resources
 }
 
 /// Retrieves the metadata name from the provided map.
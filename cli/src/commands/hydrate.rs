@@ -91,6 +91,10 @@ pub fn execute(conf: Config, openapi_file: &String) -> Result<(), AppError> {
                 YamlValue::String("x-kubernetes-operator-secret-name".to_string()),
                 YamlValue::String(conf.secret_name),
             );
+            info_map.insert(
+                YamlValue::String("x-kubernetes-operator-namespace".to_string()),
+                YamlValue::String(conf.namespace),
+            );
         }
     }
 
@@ -10,7 +10,7 @@ use crate::templates::{
         Taskfile,
     },
     operator::Main as OperatorMain,
-    operator::{Cli, Errors, Lib},
+    operator::{Cli, Conversion, Errors, Health, Lib},
     tests::{Main as TestsMain, UtilsClient, UtilsCluster, UtilsOperator},
 };
 use crate::utils::{
@@ -99,13 +99,16 @@ pub fn execute(conf: Config, path: &String) -> Result<(), AppError> {
             api_group: conf.api_group.clone(),
             api_version: conf.api_version.clone(),
             controllers: vec![],
+            controller_crds: vec![],
             types: vec![],
+            cluster_scoped: vec![],
         },
         base_path.join(K8S_OPERATOR_DIR).join("src").as_path(),
         "main.rs",
     )?;
     generate_template_file(
         CrdgenMain {
+            namespace: conf.namespace.clone(),
             resources: BTreeMap::new(),
         },
         base_path.join(K8S_CRDGEN_DIR).join("src").as_path(),
@@ -121,7 +124,9 @@ pub fn execute(conf: Config, path: &String) -> Result<(), AppError> {
 
     // Generate operator files
     generate_template_file(
-        Lib {},
+        Lib {
+            project_name: project_name.clone(),
+        },
         base_path.join(K8S_OPERATOR_DIR).join("src").as_path(),
         "lib.rs",
     )?;
@@ -139,13 +144,33 @@ pub fn execute(conf: Config, path: &String) -> Result<(), AppError> {
         base_path.join(K8S_OPERATOR_DIR).join("src").as_path(),
         "errors.rs",
     )?;
+    generate_template_file(
+        Health {},
+        base_path.join(K8S_OPERATOR_DIR).join("src").as_path(),
+        "health.rs",
+    )?;
+    generate_template_file(
+        Conversion {
+            operator_name: conf.operator_name.clone(),
+            kinds: vec![],
+        },
+        base_path.join(K8S_OPERATOR_DIR).join("src").as_path(),
+        "conversion.rs",
+    )?;
 
     // Generate root files
     generate_template_file(Dockerignore {}, base_path, ".dockerignore")?;
     generate_template_file(Editorconfig {}, base_path, ".editorconfig")?;
     generate_template_file(GitAttributes {}, base_path, ".gitattributes")?;
     generate_template_file(GitIgnore {}, base_path, ".gitignore")?;
-    generate_template_file(Taskfile {}, base_path, "Taskfile.yml")?;
+    generate_template_file(
+        Taskfile {
+            operator_name: conf.operator_name.clone(),
+            namespace: conf.namespace.clone(),
+        },
+        base_path,
+        "Taskfile.yml",
+    )?;
     generate_template_file(Prettierrc {}, base_path, ".prettierrc.yaml")?;
     generate_template_file(RustfmtToml {}, base_path, ".rustfmt.toml")?;
     generate_template_file(ClusterYaml {}, base_path, "Cluster.yaml")?;
@@ -161,6 +186,7 @@ pub fn execute(conf: Config, path: &String) -> Result<(), AppError> {
             operator_example_metadata_spec_field_ref: conf.example_metadata_spec_field_ref,
             operator_include_tags: conf.include_tags.join(","),
             operator_secret_name: conf.secret_name,
+            operator_namespace: conf.namespace,
         },
         base_path,
         ".env.example",
@@ -59,6 +59,12 @@ pub struct Config {
     /// store sensitive information, such as credentials or tokens required
     /// for its operations.
     pub secret_name: String,
+
+    /// The namespace the operator is installed into.
+    ///
+    /// Used for the namespaced manifests of the operator and the subjects of
+    /// its role bindings.
+    pub namespace: String,
 }
 
 impl Config {
@@ -96,6 +102,7 @@ impl Config {
                 "KUBERNETES_OPERATOR_SECRET_NAME",
                 "operator-secret",
             ),
+            namespace: Self::get_env_var_or_default("KUBERNETES_OPERATOR_NAMESPACE", "default"),
         })
     }
 
@@ -122,6 +129,7 @@ impl Config {
                 .clone(),
             include_tags: cli.kubernetes_operator_include_tags.clone(),
             secret_name: cli.secret_name.clone(),
+            namespace: cli.namespace.clone(),
         })
     }
 
@@ -50,8 +50,23 @@ fn main() -> Result<(), AppError> {
             manifests,
             controllers,
             types,
+            crds,
+            helm,
+            bundle,
         }) => {
-            commands::generate::execute(path, openapi_file, all, manifests, controllers, types)?;
+            commands::generate::execute(
+                path,
+                openapi_file,
+                &commands::generate::Targets {
+                    all: *all,
+                    manifests: *manifests,
+                    controllers: *controllers,
+                    types: *types,
+                    crds: *crds,
+                    helm: *helm,
+                    bundle: *bundle,
+                },
+            )?;
         }
         None => {
             info!("No command provided");
@@ -2,7 +2,7 @@ use askama::Template;
 use serde::{Deserialize, Serialize};
 use std::collections::BTreeMap;
 
-mod filters {
+pub(crate) mod filters {
     pub fn dashcase<T: std::fmt::Display>(s: T) -> ::askama::Result<String> {
         Ok(s.to_string()
             .split_whitespace()
@@ -10,17 +10,27 @@ mod filters {
             .join("-")
             .to_lowercase())
     }
+
+    /// Renders a value as a YAML block indented by `indent` spaces.
+    pub fn indented_yaml(value: &serde_json::Value, indent: usize) -> ::askama::Result<String> {
+        let yaml =
+            serde_yaml::to_string(value).map_err(|e| ::askama::Error::Custom(Box::new(e)))?;
+        Ok(yaml
+            .lines()
+            .map(|line| format!("{}{}", " ".repeat(indent), line))
+            .collect::<Vec<_>>()
+            .join("\n"))
+    }
 }
 
 // Common Identifiers and Structs
 pub struct RoleTemplateIdentifiers {
-    pub api_group: String,
-    pub resources: Vec<String>,
+    pub api_groups: BTreeMap<String, Vec<String>>,
 }
 
 pub struct ClusterRoleTemplateIdentifiers {
-    pub api_group: String,
-    pub resources: Vec<String>,
+    pub api_groups: BTreeMap<String, Vec<String>>,
+    pub install_crds: bool,
 }
 
 #[derive(Serialize, Deserialize)]
@@ -45,6 +55,34 @@ pub struct Field {
 pub struct ControllerAttributes {
     pub operation_id: String,
     pub http_method: String,
+    pub collection: bool,
+    pub idempotency_key_header: Option<String>,
+}
+
+/// The `From` conversions between a version of a kind and its hub type.
+pub struct TypeConversion {
+    pub hub: String,
+    pub hub_module: String,
+    pub common_fields: Vec<String>,
+    /// Fields of the version missing from the hub, to be mapped by hand.
+    pub version_only_fields: Vec<Field>,
+    /// Fields of the hub missing from the version, to be mapped by hand.
+    pub hub_only_fields: Vec<Field>,
+}
+
+/// A kind served in additional versions through the conversion webhook.
+pub struct ConversionKind {
+    pub kind: String,
+    pub module: String,
+    pub crd_name: String,
+    pub storage_version: String,
+    pub versions: Vec<ConversionVersion>,
+}
+
+pub struct ConversionVersion {
+    pub type_name: String,
+    pub module: String,
+    pub api_version: String,
 }
 
 // Tests Templates
@@ -103,26 +141,38 @@ pub mod manifests {
         #[derive(Template)]
         #[template(path = "manifests/rbac_role.yaml.jinja")]
         pub struct Role {
+            pub operator_name: String,
+            pub namespace: String,
             pub identifiers: RoleTemplateIdentifiers,
         }
 
         #[derive(Template)]
         #[template(path = "manifests/rbac_cluster_role.yaml.jinja")]
         pub struct ClusterRole {
+            pub operator_name: String,
             pub identifiers: ClusterRoleTemplateIdentifiers,
         }
 
         #[derive(Template)]
         #[template(path = "manifests/rbac_service_account.yaml.jinja")]
-        pub struct ServiceAccount {}
+        pub struct ServiceAccount {
+            pub operator_name: String,
+            pub namespace: String,
+        }
 
         #[derive(Template)]
         #[template(path = "manifests/rbac_role_binding.yaml.jinja")]
-        pub struct RoleBinding {}
+        pub struct RoleBinding {
+            pub operator_name: String,
+            pub namespace: String,
+        }
 
         #[derive(Template)]
         #[template(path = "manifests/rbac_cluster_role_binding.yaml.jinja")]
-        pub struct ClusterRoleBinding {}
+        pub struct ClusterRoleBinding {
+            pub operator_name: String,
+            pub namespace: String,
+        }
     }
 
     pub mod operator {
@@ -130,14 +180,49 @@ pub mod manifests {
         #[derive(Template)]
         #[template(path = "manifests/operator_deployment.yaml.jinja")]
         pub struct Deployment {
+            pub operator_name: String,
+            pub namespace: String,
             pub secret_name: String,
+            pub install_crds: bool,
+            pub resources: serde_json::Value,
+            pub pod_security_context: serde_json::Value,
+            pub security_context: serde_json::Value,
+            pub affinity: Option<serde_json::Value>,
+            pub topology_spread_constraints: Option<serde_json::Value>,
+        }
+
+        #[derive(Template)]
+        #[template(path = "manifests/operator_pod_disruption_budget.yaml.jinja")]
+        pub struct PodDisruptionBudget {
+            pub operator_name: String,
+            pub namespace: String,
+            pub budget: serde_json::Value,
+        }
+
+        #[derive(Template)]
+        #[template(path = "manifests/operator_network_policy.yaml.jinja")]
+        pub struct NetworkPolicy {
+            pub operator_name: String,
+            pub namespace: String,
+            pub api_server_cidrs: Vec<String>,
+            pub remote_host: String,
+            pub remote_port: u16,
+            pub remote_cidrs: Vec<String>,
         }
 
         #[derive(Template)]
         #[template(path = "manifests/operator_secret.yaml.jinja")]
         pub struct Secret {
+            pub namespace: String,
             pub secret_name: String,
         }
+
+        #[derive(Template)]
+        #[template(path = "manifests/operator_conversion_webhook.yaml.jinja")]
+        pub struct ConversionWebhook {
+            pub operator_name: String,
+            pub namespace: String,
+        }
     }
 
     pub mod examples {
@@ -148,6 +233,119 @@ pub mod manifests {
             pub resources: Vec<Resource>,
         }
     }
+
+    pub mod kustomize {
+        use super::*;
+
+        #[derive(Template)]
+        #[template(path = "manifests/kustomization.yaml.jinja")]
+        pub struct Kustomization {
+            pub resources: Vec<String>,
+        }
+
+        #[derive(Template)]
+        #[template(path = "manifests/kustomize_overlay_dev.yaml.jinja")]
+        pub struct OverlayDev {
+            pub operator_name: String,
+            pub namespace: String,
+        }
+
+        #[derive(Template)]
+        #[template(path = "manifests/kustomize_overlay_prod.yaml.jinja")]
+        pub struct OverlayProd {
+            pub namespace: String,
+            pub secret_name: String,
+        }
+    }
+}
+
+// Helm Chart Templates
+pub mod helm {
+    use super::*;
+
+    #[derive(Template)]
+    #[template(path = "helm/chart.yaml.jinja")]
+    pub struct Chart {
+        pub operator_name: String,
+        pub operator_author: String,
+    }
+
+    #[derive(Template)]
+    #[template(path = "helm/values.yaml.jinja")]
+    pub struct Values {
+        pub secret_name: String,
+        pub resources: serde_json::Value,
+        pub pod_security_context: serde_json::Value,
+        pub security_context: serde_json::Value,
+        pub affinity: Option<serde_json::Value>,
+        pub topology_spread_constraints: Option<serde_json::Value>,
+        pub pod_disruption_budget: Option<serde_json::Value>,
+    }
+
+    #[derive(Template)]
+    #[template(path = "helm/helpers.tpl.jinja")]
+    pub struct Helpers {}
+
+    #[derive(Template)]
+    #[template(path = "helm/deployment.yaml.jinja")]
+    pub struct Deployment {
+        pub conversion_webhook: bool,
+    }
+
+    #[derive(Template)]
+    #[template(path = "helm/pod_disruption_budget.yaml.jinja")]
+    pub struct PodDisruptionBudget {}
+
+    #[derive(Template)]
+    #[template(path = "helm/secret.yaml.jinja")]
+    pub struct Secret {}
+
+    #[derive(Template)]
+    #[template(path = "helm/service_account.yaml.jinja")]
+    pub struct ServiceAccount {}
+
+    #[derive(Template)]
+    #[template(path = "helm/role.yaml.jinja")]
+    pub struct Role {
+        pub identifiers: RoleTemplateIdentifiers,
+    }
+
+    #[derive(Template)]
+    #[template(path = "helm/role_binding.yaml.jinja")]
+    pub struct RoleBinding {}
+
+    #[derive(Template)]
+    #[template(path = "helm/cluster_role.yaml.jinja")]
+    pub struct ClusterRole {
+        pub identifiers: RoleTemplateIdentifiers,
+    }
+
+    #[derive(Template)]
+    #[template(path = "helm/cluster_role_binding.yaml.jinja")]
+    pub struct ClusterRoleBinding {}
+
+    #[derive(Template)]
+    #[template(path = "helm/conversion_webhook.yaml.jinja")]
+    pub struct ConversionWebhook {}
+}
+
+// OLM Bundle Templates
+pub mod bundle {
+    use super::*;
+
+    #[derive(Template)]
+    #[template(path = "bundle/annotations.yaml.jinja")]
+    pub struct Annotations {
+        pub package: String,
+        pub channel: String,
+    }
+
+    #[derive(Template)]
+    #[template(path = "bundle/bundle.dockerfile.jinja")]
+    pub struct Dockerfile {
+        pub package: String,
+        pub channel: String,
+    }
 }
 
 // Operator Templates
@@ -160,7 +358,9 @@ pub mod operator {
         pub api_group: String,
         pub api_version: String,
         pub controllers: Vec<String>,
+        pub controller_crds: Vec<String>,
         pub types: Vec<String>,
+        pub cluster_scoped: Vec<String>,
     }
 
     #[derive(Template)]
@@ -175,13 +375,20 @@ pub mod operator {
     #[template(path = "operator/errors.rs.jinja")]
     pub struct Errors {}
 
+    #[derive(Template)]
+    #[template(path = "operator/health.rs.jinja")]
+    pub struct Health {}
+
     #[derive(Template)]
     #[template(path = "operator/controller.rs.jinja")]
     pub struct Controller {
         pub tag: String,
+        pub api_group: String,
         pub arg_name: String,
         pub kind_struct: String,
         pub dto_fields: Vec<Field>,
+        pub drift_fields: Vec<Field>,
+        pub drift_policy: String,
         pub resource_remote_ref: String,
         pub api_url: String,
     }
@@ -207,26 +414,50 @@ pub mod operator {
     #[derive(Template)]
     #[template(path = "operator/controller_action_create.jinja")]
     pub struct ControllerActionPost<'a> {
+        pub tag: String,
         pub arg_name: String,
         pub kind_struct: String,
         pub controllers: Vec<&'a ControllerAttributes>,
         pub resource_remote_ref: String,
+        pub api_group: String,
+        pub list_operation_id: Option<String>,
+        pub natural_key: Option<String>,
     }
 
     #[derive(Template)]
     #[template(path = "operator/type.rs.jinja")]
     pub struct Type {
         pub tag_name: String,
+        pub kind: String,
+        pub conversion: Option<TypeConversion>,
+        pub singular: Option<String>,
+        pub short_names: Vec<String>,
+        pub categories: Vec<String>,
+        /// Additional printer columns as serialized JSON definitions.
+        pub print_columns: Vec<String>,
+        /// CEL validation rules of the fields as serialized JSON lists, keyed by field name.
+        pub validations: BTreeMap<String, String>,
         pub type_name: String,
         pub api_version: String,
         pub group_name: String,
         pub fields: Vec<Field>,
         pub reference_id: String,
+        pub deletion_policy: String,
+        pub cluster_scoped: bool,
+    }
+
+    #[derive(Template)]
+    #[template(path = "operator/conversion.rs.jinja")]
+    pub struct Conversion {
+        pub operator_name: String,
+        pub kinds: Vec<ConversionKind>,
     }
 
     #[derive(Template)]
     #[template(path = "operator/lib.rs.jinja")]
-    pub struct Lib {}
+    pub struct Lib {
+        pub project_name: String,
+    }
 }
 
 // Cargo Templates
@@ -257,6 +488,7 @@ pub mod crdgen {
     #[derive(Template)]
     #[template(path = "crdgen/main.rs.jinja")]
     pub struct Main {
+        pub namespace: String,
         pub resources: BTreeMap<String, String>,
     }
 }
@@ -284,6 +516,7 @@ pub mod general {
         pub operator_example_metadata_spec_field_ref: String,
         pub operator_include_tags: String,
         pub operator_secret_name: String,
+        pub operator_namespace: String,
     }
 
     #[derive(Template)]
@@ -322,7 +555,10 @@ pub mod general {
 
     #[derive(Template)]
     #[template(path = "taskfile.yml.jinja")]
-    pub struct Taskfile {}
+    pub struct Taskfile {
+        pub operator_name: String,
+        pub namespace: String,
+    }
 
     #[derive(Template)]
     #[template(path = ".cargo/config.toml.jinja")]
@@ -193,6 +193,21 @@ pub fn extract_config_from_openapi(openapi: &OpenAPI) -> Result<Config, AppError
         "x-kubernetes-operator-example-metadata-spec-field-ref",
     )?;
     let secret_name = extract_extension(openapi, "x-kubernetes-operator-secret-name")?;
+    let namespace = match openapi
+        .info
+        .extensions
+        .get("x-kubernetes-operator-namespace")
+    {
+        None => "default".to_string(),
+        Some(value) => value
+            .as_str()
+            .ok_or_else(|| {
+                AppError::Other(
+                    "The 'x-kubernetes-operator-namespace' extension is not a string.".to_string(),
+                )
+            })?
+            .to_string(),
+    };
     Ok(Config {
         operator_name,
         operator_author,
@@ -202,6 +217,7 @@ pub fn extract_config_from_openapi(openapi: &OpenAPI) -> Result<Config, AppError
         include_tags,
         example_metadata_spec_field_ref,
         secret_name,
+        namespace,
     })
 }
 
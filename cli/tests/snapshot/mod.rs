@@ -1,9 +1,15 @@
 mod templates_env_example;
 
+/// Manifests
+mod templates_manifests_rbac;
+
 /// Operator
 mod templates_operator_cargo_toml;
 mod templates_operator_cli;
 mod templates_operator_controller;
+mod templates_operator_controller_action_create;
+mod templates_operator_conversion;
+mod templates_operator_health;
 mod templates_operator_lib;
 mod templates_operator_main;
 mod templates_operator_type;
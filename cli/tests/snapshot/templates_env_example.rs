@@ -13,6 +13,7 @@ fn render_env_example() -> Result<(), AppError> {
         operator_example_metadata_spec_field_ref: "fieldRef".to_string(),
         operator_include_tags: "tag1".to_string(),
         operator_secret_name: "secret".to_string(),
+        operator_namespace: "operators".to_string(),
     };
     let rendered = template.render()?;
     assert_snapshot!(rendered);
@@ -30,6 +31,7 @@ fn render_env_example_with_empty_string() -> Result<(), AppError> {
         operator_example_metadata_spec_field_ref: "".to_string(),
         operator_include_tags: "".to_string(),
         operator_secret_name: "".to_string(),
+        operator_namespace: "".to_string(),
     };
     let rendered = template.render()?;
     assert_snapshot!(rendered);
@@ -0,0 +1,58 @@
+use askama::Template;
+use insta::assert_snapshot;
+use kopgen::{
+    errors::AppError,
+    templates::{
+        manifests::rbac::{ClusterRole, Role},
+        ClusterRoleTemplateIdentifiers, RoleTemplateIdentifiers,
+    },
+};
+use std::collections::BTreeMap;
+
+#[test]
+fn render_role() -> Result<(), AppError> {
+    let template = Role {
+        operator_name: "Cats Operator".to_string(),
+        namespace: "cats".to_string(),
+        identifiers: RoleTemplateIdentifiers {
+            api_groups: BTreeMap::from([(
+                "example.com".to_string(),
+                vec!["cats".to_string(), "dogs".to_string()],
+            )]),
+        },
+    };
+
+    let rendered = template.render()?;
+    assert_snapshot!(rendered);
+    Ok(())
+}
+
+#[test]
+fn render_cluster_role_installing_crds() -> Result<(), AppError> {
+    let template = ClusterRole {
+        operator_name: "Cats Operator".to_string(),
+        identifiers: ClusterRoleTemplateIdentifiers {
+            api_groups: BTreeMap::from([("example.com".to_string(), vec!["geese".to_string()])]),
+            install_crds: true,
+        },
+    };
+
+    let rendered = template.render()?;
+    assert_snapshot!(rendered);
+    Ok(())
+}
+
+#[test]
+fn render_cluster_role_without_installing_crds() -> Result<(), AppError> {
+    let template = ClusterRole {
+        operator_name: "Cats Operator".to_string(),
+        identifiers: ClusterRoleTemplateIdentifiers {
+            api_groups: BTreeMap::new(),
+            install_crds: false,
+        },
+    };
+
+    let rendered = template.render()?;
+    assert_snapshot!(rendered);
+    Ok(())
+}
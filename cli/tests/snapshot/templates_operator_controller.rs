@@ -9,6 +9,7 @@ use kopgen::{
 fn render() -> Result<(), AppError> {
     let template = Controller {
         tag: "example_tag".to_string(),
+        api_group: "example.com".to_string(),
         arg_name: "argName".to_string(),
         kind_struct: "ExampleKind".to_string(),
         dto_fields: vec![
@@ -21,6 +22,11 @@ fn render() -> Result<(), AppError> {
                 field_type: "string".to_string(),
             },
         ],
+        drift_fields: vec![Field {
+            pub_name: "field1".to_string(),
+            field_type: "string".to_string(),
+        }],
+        drift_policy: "Correct".to_string(),
         resource_remote_ref: "resourceRef".to_string(),
         api_url: "https://api.example.com".to_string(),
     };
@@ -0,0 +1,91 @@
+use askama::Template;
+use insta::assert_snapshot;
+use kopgen::{
+    errors::AppError,
+    templates::{operator::ControllerActionPost, ControllerAttributes},
+};
+
+#[test]
+fn render_with_natural_key_lookup() -> Result<(), AppError> {
+    let create = ControllerAttributes {
+        operation_id: "create_cat".to_string(),
+        http_method: "post".to_string(),
+        collection: true,
+        idempotency_key_header: Some("Idempotency-Key".to_string()),
+    };
+    let template = ControllerActionPost {
+        tag: "cats".to_string(),
+        arg_name: "cat".to_string(),
+        kind_struct: "Cat".to_string(),
+        controllers: vec![&create],
+        resource_remote_ref: "uuid".to_string(),
+        api_group: "example.com".to_string(),
+        list_operation_id: Some("get_cats".to_string()),
+        natural_key: Some("name".to_string()),
+    };
+
+    let rendered = template.render()?;
+    assert_snapshot!(rendered);
+    Ok(())
+}
+
+#[test]
+fn render_without_natural_key_lookup() -> Result<(), AppError> {
+    let create = ControllerAttributes {
+        operation_id: "create_cat".to_string(),
+        http_method: "post".to_string(),
+        collection: true,
+        idempotency_key_header: None,
+    };
+    let template = ControllerActionPost {
+        tag: "cats".to_string(),
+        arg_name: "cat".to_string(),
+        kind_struct: "Cat".to_string(),
+        controllers: vec![&create],
+        resource_remote_ref: "uuid".to_string(),
+        api_group: "example.com".to_string(),
+        list_operation_id: None,
+        natural_key: None,
+    };
+
+    let rendered = template.render()?;
+    assert_snapshot!(rendered);
+    Ok(())
+}
+
+#[test]
+fn render_record_created_keeps_remote_ref_for_event() -> Result<(), AppError> {
+    let create = ControllerAttributes {
+        operation_id: "create_cat".to_string(),
+        http_method: "post".to_string(),
+        collection: true,
+        idempotency_key_header: None,
+    };
+    let template = ControllerActionPost {
+        tag: "cats".to_string(),
+        arg_name: "cat".to_string(),
+        kind_struct: "Cat".to_string(),
+        controllers: vec![&create],
+        resource_remote_ref: "uuid".to_string(),
+        api_group: "example.com".to_string(),
+        list_operation_id: None,
+        natural_key: None,
+    };
+
+    let rendered = template.render()?;
+    let record_created = rendered
+        .split("async fn record_created")
+        .nth(1)
+        .and_then(|body| body.split("\n}\n").next())
+        .expect("record_created is not rendered");
+    // The remote reference is used for the event after the status update, so it must not be moved.
+    let status_update = record_created
+        .find("status.uuid = ")
+        .expect("record_created does not set the remote reference");
+    assert!(
+        record_created[status_update..].starts_with("status.uuid = Some(uuid.clone());"),
+        "The remote reference is moved into the status."
+    );
+    assert!(record_created[status_update..].contains("Created remote cat {}\", uuid)"));
+    Ok(())
+}
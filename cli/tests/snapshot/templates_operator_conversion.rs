@@ -0,0 +1,40 @@
+use askama::Template;
+use insta::assert_snapshot;
+use kopgen::{
+    errors::AppError,
+    templates::{operator::Conversion, ConversionKind, ConversionVersion},
+};
+
+#[test]
+fn render_without_versions() -> Result<(), AppError> {
+    let template = Conversion {
+        operator_name: "Cats Operator".to_string(),
+        kinds: vec![],
+    };
+
+    let rendered = template.render()?;
+    assert_snapshot!(rendered);
+    Ok(())
+}
+
+#[test]
+fn render_with_versions() -> Result<(), AppError> {
+    let template = Conversion {
+        operator_name: "Cats Operator".to_string(),
+        kinds: vec![ConversionKind {
+            kind: "Cat".to_string(),
+            module: "cat".to_string(),
+            crd_name: "cats.example.com".to_string(),
+            storage_version: "v1".to_string(),
+            versions: vec![ConversionVersion {
+                type_name: "CatV1alpha1".to_string(),
+                module: "catv1alpha1".to_string(),
+                api_version: "example.com/v1alpha1".to_string(),
+            }],
+        }],
+    };
+
+    let rendered = template.render()?;
+    assert_snapshot!(rendered);
+    Ok(())
+}
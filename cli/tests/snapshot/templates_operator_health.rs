@@ -0,0 +1,12 @@
+use askama::Template;
+use insta::assert_snapshot;
+use kopgen::{errors::AppError, templates::operator::Health};
+
+#[test]
+fn render() -> Result<(), AppError> {
+    let template = Health {};
+
+    let rendered = template.render()?;
+    assert_snapshot!(rendered);
+    Ok(())
+}
@@ -4,7 +4,9 @@ use kopgen::{errors::AppError, templates::operator::Lib};
 
 #[test]
 fn render() -> Result<(), AppError> {
-    let template = Lib {};
+    let template = Lib {
+        project_name: "Example Operator".to_string(),
+    };
 
     let rendered = template.render()?;
     assert_snapshot!(rendered);
@@ -8,7 +8,12 @@ fn render_with_controllers_and_types() -> Result<(), AppError> {
         api_group: "example.com".to_string(),
         api_version: "v1".to_string(),
         controllers: vec!["controller1".to_string(), "controller2".to_string()],
+        controller_crds: vec![
+            "controller1.example.com".to_string(),
+            "controller2.example.com".to_string(),
+        ],
         types: vec!["Type1".to_string(), "Type2".to_string()],
+        cluster_scoped: vec![],
     };
 
     let rendered = template.render()?;
@@ -22,7 +27,12 @@ fn render_with_controllers() -> Result<(), AppError> {
         api_group: "example.com".to_string(),
         api_version: "v1".to_string(),
         controllers: vec!["controller1".to_string(), "controller2".to_string()],
+        controller_crds: vec![
+            "controller1.example.com".to_string(),
+            "controller2.example.com".to_string(),
+        ],
         types: vec![],
+        cluster_scoped: vec!["controller2".to_string()],
     };
 
     let rendered = template.render()?;
@@ -36,7 +46,9 @@ fn render_with_types() -> Result<(), AppError> {
         api_group: "example.com".to_string(),
         api_version: "v1".to_string(),
         controllers: vec![],
+        controller_crds: vec![],
         types: vec!["Type1".to_string(), "Type2".to_string()],
+        cluster_scoped: vec![],
     };
 
     let rendered = template.render()?;
@@ -50,7 +62,9 @@ fn render_without_controllers_or_types() -> Result<(), AppError> {
         api_group: "example.com".to_string(),
         api_version: "v1".to_string(),
         controllers: vec![],
+        controller_crds: vec![],
         types: vec![],
+        cluster_scoped: vec![],
     };
 
     let rendered = template.render()?;
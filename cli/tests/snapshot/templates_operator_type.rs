@@ -2,17 +2,27 @@ use askama::Template;
 use insta::assert_snapshot;
 use kopgen::{
     errors::AppError,
-    templates::{operator::Type, Field},
+    templates::{operator::Type, Field, TypeConversion},
 };
+use std::collections::BTreeMap;
 
 #[test]
 fn render_basic_type() -> Result<(), AppError> {
     let template = Type {
         tag_name: "basic".to_string(),
+        kind: "BasicType".to_string(),
+        conversion: None,
+        singular: None,
+        short_names: vec![],
+        categories: vec![],
+        print_columns: vec![],
+        validations: BTreeMap::new(),
         api_version: "v1".to_string(),
         group_name: "example.com".to_string(),
         reference_id: "basic-type-001".to_string(),
         type_name: "BasicType".to_string(),
+        deletion_policy: "Delete".to_string(),
+        cluster_scoped: false,
         fields: vec![
             Field {
                 pub_name: "id".to_string(),
@@ -34,10 +44,19 @@ fn render_basic_type() -> Result<(), AppError> {
 fn render_complex_type() -> Result<(), AppError> {
     let template = Type {
         tag_name: "complex".to_string(),
+        kind: "ComplexType".to_string(),
+        conversion: None,
+        singular: None,
+        short_names: vec![],
+        categories: vec![],
+        print_columns: vec![],
+        validations: BTreeMap::new(),
         api_version: "v1".to_string(),
         group_name: "example.com".to_string(),
         reference_id: "complex-type-001".to_string(),
         type_name: "ComplexType".to_string(),
+        deletion_policy: "Orphan".to_string(),
+        cluster_scoped: false,
         fields: vec![
             Field {
                 pub_name: "id".to_string(),
@@ -58,3 +77,160 @@ fn render_complex_type() -> Result<(), AppError> {
     assert_snapshot!(rendered);
     Ok(())
 }
+
+#[test]
+fn render_cluster_scoped_type() -> Result<(), AppError> {
+    let template = Type {
+        tag_name: "organizations".to_string(),
+        kind: "Organization".to_string(),
+        conversion: None,
+        singular: None,
+        short_names: vec![],
+        categories: vec![],
+        print_columns: vec![],
+        validations: BTreeMap::new(),
+        api_version: "v1".to_string(),
+        group_name: "example.com".to_string(),
+        reference_id: "uuid".to_string(),
+        type_name: "Organization".to_string(),
+        deletion_policy: "Delete".to_string(),
+        cluster_scoped: true,
+        fields: vec![Field {
+            pub_name: "name".to_string(),
+            field_type: "String".to_string(),
+        }],
+    };
+
+    let rendered = template.render()?;
+    assert_snapshot!(rendered);
+    Ok(())
+}
+
+#[test]
+fn render_type_with_crd_metadata() -> Result<(), AppError> {
+    let template = Type {
+        tag_name: "geese".to_string(),
+        kind: "Goose".to_string(),
+        conversion: None,
+        singular: Some("goose".to_string()),
+        short_names: vec!["gs".to_string()],
+        categories: vec!["all".to_string(), "birds".to_string()],
+        validations: BTreeMap::new(),
+        print_columns: vec![
+            r#"{"name":"Name","type":"string","jsonPath":".spec.name"}"#.to_string()
+        ],
+        api_version: "v1".to_string(),
+        group_name: "example.com".to_string(),
+        reference_id: "uuid".to_string(),
+        type_name: "Goose".to_string(),
+        deletion_policy: "Delete".to_string(),
+        cluster_scoped: false,
+        fields: vec![Field {
+            pub_name: "name".to_string(),
+            field_type: "String".to_string(),
+        }],
+    };
+
+    let rendered = template.render()?;
+    assert_snapshot!(rendered);
+    Ok(())
+}
+
+#[test]
+fn render_type_with_conversion() -> Result<(), AppError> {
+    let template = Type {
+        tag_name: "cats".to_string(),
+        kind: "Cat".to_string(),
+        conversion: Some(TypeConversion {
+            hub: "Cat".to_string(),
+            hub_module: "cat".to_string(),
+            common_fields: vec!["name".to_string()],
+            version_only_fields: vec![Field {
+                pub_name: "nickname".to_string(),
+                field_type: "Option<String>".to_string(),
+            }],
+            hub_only_fields: vec![
+                Field {
+                    pub_name: "age".to_string(),
+                    field_type: "i32".to_string(),
+                },
+                Field {
+                    pub_name: "color".to_string(),
+                    field_type: "Option<String>".to_string(),
+                },
+            ],
+        }),
+        singular: None,
+        short_names: vec![],
+        categories: vec![],
+        print_columns: vec![],
+        validations: BTreeMap::new(),
+        api_version: "v1alpha1".to_string(),
+        group_name: "example.com".to_string(),
+        reference_id: "uuid".to_string(),
+        type_name: "CatV1alpha1".to_string(),
+        deletion_policy: "Delete".to_string(),
+        cluster_scoped: false,
+        fields: vec![
+            Field {
+                pub_name: "name".to_string(),
+                field_type: "Option<String>".to_string(),
+            },
+            Field {
+                pub_name: "nickname".to_string(),
+                field_type: "Option<String>".to_string(),
+            },
+        ],
+    };
+
+    let rendered = template.render()?;
+    assert_snapshot!(rendered);
+    Ok(())
+}
+
+#[test]
+fn render_type_with_validations() -> Result<(), AppError> {
+    let template = Type {
+        tag_name: "clusters".to_string(),
+        kind: "Cluster".to_string(),
+        conversion: None,
+        singular: None,
+        short_names: vec![],
+        categories: vec![],
+        print_columns: vec![],
+        validations: BTreeMap::from([
+            (
+                "region".to_string(),
+                r#"[{"message":"region is immutable","rule":"self == oldSelf"}]"#.to_string(),
+            ),
+            (
+                "replicas".to_string(),
+                r#"[{"rule":"self >= 1"}]"#.to_string(),
+            ),
+        ]),
+        api_version: "v1".to_string(),
+        group_name: "example.com".to_string(),
+        reference_id: "uuid".to_string(),
+        type_name: "Cluster".to_string(),
+        deletion_policy: "Delete".to_string(),
+        cluster_scoped: false,
+        fields: vec![
+            Field {
+                pub_name: "name".to_string(),
+                field_type: "String".to_string(),
+            },
+            Field {
+                pub_name: "region".to_string(),
+                field_type: "String".to_string(),
+            },
+            Field {
+                pub_name: "replicas".to_string(),
+                field_type: "Option<i32>".to_string(),
+            },
+        ],
+    };
+
+    let rendered = template.render()?;
+    assert_snapshot!(rendered);
+    Ok(())
+}
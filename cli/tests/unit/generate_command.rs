@@ -3,18 +3,66 @@
 mod tests {
     use crate::utils::create_temp_file;
     use kopgen::{
-        commands::generate::{execute, generate_types},
+        commands::generate::{
+            execute, generate_bundle, generate_crdgen_fixtures, generate_crds, generate_helm_chart,
+            generate_types, get_deployment_settings, Targets,
+        },
         errors::AppError,
-        utils::read_openapi_spec,
+        utils::{extract_config_from_openapi, read_openapi_spec},
     };
     use openapiv3::Schema;
     use serial_test::serial;
-    use std::{collections::HashMap, fs};
+    use std::{collections::HashMap, fs, path::Path};
     use tempfile::tempdir;
 
     /// Helper function to set common parameters for `execute`.
-    fn default_params() -> (bool, bool, bool, bool) {
-        (true, false, false, false)
+    fn default_params() -> Targets {
+        Targets {
+            all: true,
+            manifests: false,
+            controllers: false,
+            types: false,
+            crds: false,
+            helm: false,
+            bundle: false,
+        }
+    }
+
+    /// Builds an OpenAPI spec with the info extensions `execute` requires, followed by the info
+    /// extensions a test exercises and the rest of the spec.
+    fn build_openapi_yaml(info_extensions: &str, spec: &str) -> String {
+        format!(
+            r#"
+openapi: 3.0.0
+info:
+  title: Test API
+  version: 1.0.0
+  x-kubernetes-operator-name: Test Operator
+  x-kubernetes-operator-author: Test Author
+  x-kubernetes-operator-api-group: example.com
+  x-kubernetes-operator-api-version: v1
+  x-kubernetes-operator-resource-ref: uuid
+  x-kubernetes-operator-example-metadata-spec-field-ref: name
+  x-kubernetes-operator-include-tags: [cats]
+  x-kubernetes-operator-secret-name: operator-secret
+{}
+{}"#,
+            info_extensions, spec
+        )
+    }
+
+    /// Reads the schemas of the components of the OpenAPI spec at `openapi_file`.
+    fn read_schemas(openapi_file: &str) -> Result<HashMap<String, Schema>, AppError> {
+        Ok(read_openapi_spec(openapi_file)?
+            .components
+            .ok_or_else(|| AppError::Other("No components found in OpenAPI spec".to_string()))?
+            .schemas
+            .iter()
+            .filter_map(|(name, schema)| match schema {
+                openapiv3::ReferenceOr::Item(schema) => Some((name.clone(), schema.clone())),
+                openapiv3::ReferenceOr::Reference { .. } => None,
+            })
+            .collect())
     }
 
     /// Tests that `execute` fails when the Kubernetes extension is missing from the OpenAPI spec.
@@ -32,15 +80,12 @@ components:
 "#;
 
         let (dir, openapi_file) = create_temp_file("openapi.yaml", openapi_yaml);
-        let (all, manifests, controllers, types) = default_params();
+        let targets = default_params();
 
         let result = execute(
             &dir.path().to_string_lossy().to_string(),
             &openapi_file,
-            &all,
-            &manifests,
-            &controllers,
-            &types,
+            &targets,
         );
 
         assert!(
@@ -67,15 +112,12 @@ components:
         let dir = tempdir()?;
         let openapi_file = dir.path().join("missing_openapi.yaml");
 
-        let (all, manifests, controllers, types) = default_params();
+        let targets = default_params();
 
         let result = execute(
             &dir.path().to_string_lossy().to_string(),
             &openapi_file.to_string_lossy().to_string(),
-            &all,
-            &manifests,
-            &controllers,
-            &types,
+            &targets,
         );
 
         assert!(
@@ -94,15 +136,12 @@ components:
 invalid_yaml: [unclosed_list
 "#;
         let (dir, openapi_file) = create_temp_file("invalid_openapi.yaml", openapi_yaml);
-        let (all, manifests, controllers, types) = default_params();
+        let targets = default_params();
 
         let result = execute(
             &dir.path().to_string_lossy().to_string(),
             &openapi_file,
-            &all,
-            &manifests,
-            &controllers,
-            &types,
+            &targets,
         );
 
         assert!(
@@ -148,24 +187,20 @@ components:
         let output_path = dir.path().join("src").join("types");
         fs::create_dir_all(&output_path)?;
 
-        let openapi = read_openapi_spec(openapi_file_path.as_str())?;
-        let schemas: HashMap<String, Schema> = openapi
-            .components
-            .ok_or_else(|| AppError::Other("No components found in OpenAPI spec".to_string()))?
-            .schemas
-            .iter()
-            .filter_map(|(name, schema)| match schema {
-                openapiv3::ReferenceOr::Item(schema) => Some((name.clone(), schema.clone())),
-                openapiv3::ReferenceOr::Reference { .. } => None,
-            })
-            .collect();
+        let schemas = read_schemas(&openapi_file_path)?;
 
         let operator_resource_ref = "id".to_string();
         let types_directory = output_path
             .to_str()
             .expect("Failed to convert output path to string");
 
-        generate_types(types_directory, &schemas, &operator_resource_ref)?;
+        generate_types(
+            types_directory,
+            &schemas,
+            &operator_resource_ref,
+            "example.com",
+            "v1",
+        )?;
 
         let type_file = output_path.join("user.rs");
         assert!(type_file.exists(), "Type file was not created.");
@@ -179,67 +214,1657 @@ components:
         Ok(())
     }
 
+    /// Tests that `generate_types` uses the deletion policy declared on the schema as the default.
     #[test]
     #[serial]
-    fn test_parsing_json_spec() -> Result<(), AppError> {
-        let openapi_json = r#"{
-    "openapi": "3.0.0",
-    "info": {
-        "title": "Test API",
-        "version": "1.0.0",
-        "x-kubernetes-operator-api-group": "testgroup",
-        "x-kubernetes-operator-api-version": "v1",
-        "x-kubernetes-operator-resource-ref": "uuid",
-        "x-kubernetes-operator-status-ref": "status",
-        "x-kubernetes-operator-include-tags": ["user"],
-        "x-kubernetes-operator-example-metadata-spec-field-ref": "metadata"
-    },
-    "paths": {},
-    "components": {
-        "schemas": {
-            "User": {
-                "type": "object",
-                "properties": {
-                    "id": {
-                        "type": "integer",
-                        "format": "int64"
-                    },
-                    "name": {
-                        "type": "string"
-                    },
-                    "email": {
-                        "type": "string",
-                        "format": "email"
-                    }
-                },
-                "required": ["id"]
+    fn test_generate_types_with_deletion_policy() -> Result<(), AppError> {
+        let openapi_yaml = r#"
+openapi: 3.0.0
+info:
+  title: Test API
+  version: 1.0.0
+paths: {}
+components:
+  schemas:
+    Database:
+      type: object
+      x-kubernetes-operator-deletion-policy: Orphan
+      properties:
+        name:
+          type: string
+"#;
+
+        let (dir, openapi_file_path) = create_temp_file("openapi.yaml", openapi_yaml);
+        let output_path = dir.path().join("src").join("types");
+        fs::create_dir_all(&output_path)?;
+
+        let schemas = read_schemas(&openapi_file_path)?;
+
+        let types_directory = output_path
+            .to_str()
+            .expect("Failed to convert output path to string");
+
+        generate_types(types_directory, &schemas, "uuid", "example.com", "v1")?;
+
+        let generated_content = fs::read_to_string(output_path.join("database.rs"))?;
+        assert!(
+            generated_content.contains("DeletionPolicy::Orphan"),
+            "Generated content does not default to the Orphan deletion policy."
+        );
+
+        Ok(())
+    }
+
+    /// Builds a spec with a `cats` controller whose `Cat` schema declares the given extensions.
+    fn drift_policy_openapi_yaml(extensions: &str) -> String {
+        build_openapi_yaml(
+            "",
+            &format!(
+                r#"
+paths:
+  /cats:
+    get:
+      tags: [cats]
+      operationId: getCats
+      responses:
+        '200':
+          description: OK
+    post:
+      tags: [cats]
+      operationId: createCat
+      responses:
+        '201':
+          description: Created
+components:
+  schemas:
+    Cat:
+      type: object
+{}
+      properties:
+        uuid:
+          type: string
+        name:
+          type: string
+        age:
+          type: integer
+"#,
+                extensions
+            ),
+        )
+    }
+
+    /// Runs `execute` for the controllers of a spec with the given drift extensions on `Cat`.
+    fn execute_controllers_with_drift_extensions(
+        extensions: &str,
+    ) -> Result<tempfile::TempDir, AppError> {
+        let (dir, openapi_file) =
+            create_temp_file("openapi.yaml", &drift_policy_openapi_yaml(extensions));
+        let controllers_path = dir.path().join("operator").join("src").join("controllers");
+        fs::create_dir_all(&controllers_path)?;
+        fs::write(controllers_path.join("mod.rs"), "")?;
+        fs::write(dir.path().join(".openapi-generator-ignore"), "")?;
+        fs::write(dir.path().join("rustfmt.toml"), "edition = \"2021\"\n")?;
+
+        let targets = Targets {
+            all: false,
+            manifests: false,
+            controllers: true,
+            types: false,
+            crds: false,
+            helm: false,
+            bundle: false,
+        };
+        execute(
+            &dir.path().to_string_lossy().to_string(),
+            &openapi_file,
+            &targets,
+        )?;
+        Ok(dir)
+    }
+
+    /// Tests that the controller defaults to the drift policy of the schema and leaves the
+    /// ignored fields out of the drift comparison.
+    #[test]
+    #[serial]
+    fn test_execute_generates_controller_with_drift_policy() -> Result<(), AppError> {
+        let dir = execute_controllers_with_drift_extensions(
+            "      x-kubernetes-operator-drift-policy: Report\n      x-kubernetes-operator-drift-ignore-fields: [age]",
+        )?;
+
+        let controller = fs::read_to_string(
+            dir.path()
+                .join("operator")
+                .join("src")
+                .join("controllers")
+                .join("cats.rs"),
+        )?;
+        assert!(
+            controller.contains("const DEFAULT_DRIFT_POLICY: DriftPolicy = DriftPolicy::Report;"),
+            "Controller does not default to the Report drift policy."
+        );
+        assert!(
+            controller.contains("if local.name != remote.name {"),
+            "Controller does not compare name for drift."
+        );
+        assert!(
+            !controller.contains("local.age"),
+            "Controller compares the ignored age field for drift."
+        );
+
+        Ok(())
+    }
+
+    /// Tests that a controller without a list operation reports pending creates instead of
+    /// creating them again, while one with a list operation looks them up.
+    #[test]
+    #[serial]
+    fn test_execute_generates_pending_create_lookup() -> Result<(), AppError> {
+        let controller_path = |dir: &tempfile::TempDir| {
+            dir.path()
+                .join("operator")
+                .join("src")
+                .join("controllers")
+                .join("cats.rs")
+        };
+
+        let dir = execute_controllers_with_drift_extensions("")?;
+        let controller = fs::read_to_string(controller_path(&dir))?;
+        assert!(
+            controller.contains("find_existing_cat(&config, &dto)"),
+            "Controller does not look up pending creates through the list operation."
+        );
+
+        let openapi_yaml = drift_policy_openapi_yaml("").replace(
+            "    get:\n      tags: [cats]\n      operationId: getCats\n      responses:\n        '200':\n          description: OK\n",
+            "",
+        );
+        let (dir, openapi_file) = create_temp_file("openapi.yaml", &openapi_yaml);
+        fs::create_dir_all(controller_path(&dir).parent().unwrap())?;
+        fs::write(controller_path(&dir).with_file_name("mod.rs"), "")?;
+        fs::write(dir.path().join(".openapi-generator-ignore"), "")?;
+        fs::write(dir.path().join("rustfmt.toml"), "edition = \"2021\"\n")?;
+        let targets = Targets {
+            all: false,
+            manifests: false,
+            controllers: true,
+            types: false,
+            crds: false,
+            helm: false,
+            bundle: false,
+        };
+        execute(
+            &dir.path().to_string_lossy().to_string(),
+            &openapi_file,
+            &targets,
+        )?;
+
+        let controller = fs::read_to_string(controller_path(&dir))?;
+        assert!(
+            !controller.contains("find_existing_cat"),
+            "Controller looks up pending creates without a list operation."
+        );
+        assert!(
+            controller.contains("may have succeeded but cannot be looked up"),
+            "Controller does not report pending creates it cannot look up."
+        );
+
+        Ok(())
+    }
+
+    /// Tests that `execute` rejects drift policies which are unknown or not a string.
+    #[test]
+    #[serial]
+    fn test_execute_fails_invalid_drift_policy() -> Result<(), AppError> {
+        for drift_policy in ["Fix", "true", "[Report]"] {
+            let result = execute_controllers_with_drift_extensions(&format!(
+                "      x-kubernetes-operator-drift-policy: {}",
+                drift_policy
+            ));
+            match result {
+                Err(AppError::ConfigError(message)) => assert!(
+                    message.contains("Invalid x-kubernetes-operator-drift-policy")
+                        && message.contains("expected one of: Correct, Report, Ignore"),
+                    "Unexpected error message for {}: {}",
+                    drift_policy,
+                    message
+                ),
+                Err(e) => panic!("Expected ConfigError for {}, got {:?}", drift_policy, e),
+                Ok(_) => panic!("Expected drift policy {} to be rejected.", drift_policy),
             }
         }
+
+        Ok(())
     }
-}"#;
 
-        let (dir, openapi_file) = create_temp_file("openapi.json", openapi_json);
+    /// Tests that `execute` rejects drift ignore fields which are not a list of strings.
+    #[test]
+    #[serial]
+    fn test_execute_fails_invalid_drift_ignore_fields() -> Result<(), AppError> {
+        for (ignore_fields, expected) in [
+            (
+                "age",
+                "The 'x-kubernetes-operator-drift-ignore-fields' extension is not an array.",
+            ),
+            (
+                "{age: true}",
+                "The 'x-kubernetes-operator-drift-ignore-fields' extension is not an array.",
+            ),
+            (
+                "[age, 1]",
+                "An element in 'x-kubernetes-operator-drift-ignore-fields' is not a string.",
+            ),
+        ] {
+            let result = execute_controllers_with_drift_extensions(&format!(
+                "      x-kubernetes-operator-drift-ignore-fields: {}",
+                ignore_fields
+            ));
+            match result {
+                Err(AppError::ConfigError(message)) => assert_eq!(
+                    message, expected,
+                    "Unexpected error message for {}",
+                    ignore_fields
+                ),
+                Err(e) => panic!("Expected ConfigError for {}, got {:?}", ignore_fields, e),
+                Ok(_) => panic!(
+                    "Expected drift ignore fields {} to be rejected.",
+                    ignore_fields
+                ),
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Tests that `generate_types` omits `namespaced` for schemas with the Cluster scope.
+    #[test]
+    #[serial]
+    fn test_generate_types_with_cluster_scope() -> Result<(), AppError> {
+        let openapi_yaml = r#"
+openapi: 3.0.0
+info:
+  title: Test API
+  version: 1.0.0
+paths: {}
+components:
+  schemas:
+    Organization:
+      type: object
+      x-kubernetes-operator-scope: Cluster
+      properties:
+        name:
+          type: string
+    Database:
+      type: object
+      properties:
+        name:
+          type: string
+"#;
+
+        let (dir, openapi_file_path) = create_temp_file("openapi.yaml", openapi_yaml);
         let output_path = dir.path().join("src").join("types");
         fs::create_dir_all(&output_path)?;
 
-        let openapi = read_openapi_spec(openapi_file.as_str())?;
-        let schemas: HashMap<String, Schema> = openapi
-            .components
-            .ok_or_else(|| AppError::Other("No components found in OpenAPI spec".to_string()))?
-            .schemas
-            .iter()
-            .filter_map(|(name, schema)| match schema {
-                openapiv3::ReferenceOr::Item(schema) => Some((name.clone(), schema.clone())),
-                openapiv3::ReferenceOr::Reference { .. } => None,
-            })
-            .collect();
+        let schemas = read_schemas(&openapi_file_path)?;
+
+        let types_directory = output_path
+            .to_str()
+            .expect("Failed to convert output path to string");
+
+        generate_types(types_directory, &schemas, "uuid", "example.com", "v1")?;
+
+        let organization = fs::read_to_string(output_path.join("organization.rs"))?;
+        assert!(
+            !organization.contains("namespaced"),
+            "Cluster-scoped type should not be namespaced."
+        );
+        let database = fs::read_to_string(output_path.join("database.rs"))?;
+        assert!(
+            database.contains("namespaced"),
+            "Type without a scope should be namespaced."
+        );
+
+        Ok(())
+    }
+
+    /// Tests that `generate_types` applies the CRD metadata extensions declared on the schema.
+    #[test]
+    #[serial]
+    fn test_generate_types_with_crd_metadata() -> Result<(), AppError> {
+        let openapi_yaml = r#"
+openapi: 3.0.0
+info:
+  title: Test API
+  version: 1.0.0
+paths: {}
+components:
+  schemas:
+    Goose:
+      type: object
+      x-kubernetes-operator-plural: geese
+      x-kubernetes-operator-short-names: [gs]
+      x-kubernetes-operator-categories: [birds]
+      x-kubernetes-operator-print-columns:
+        - name: Name
+          jsonPath: .spec.name
+      properties:
+        name:
+          type: string
+"#;
+
+        let (dir, openapi_file_path) = create_temp_file("openapi.yaml", openapi_yaml);
+        let output_path = dir.path().join("src").join("types");
+        fs::create_dir_all(&output_path)?;
+
+        let schemas = read_schemas(&openapi_file_path)?;
+
+        let types_directory = output_path
+            .to_str()
+            .expect("Failed to convert output path to string");
+
+        generate_types(types_directory, &schemas, "uuid", "example.com", "v1")?;
+
+        let generated_content = fs::read_to_string(output_path.join("goose.rs"))?;
+        for expected in [
+            r#"plural = "geese""#,
+            r#"shortname = "gs""#,
+            r#"category = "birds""#,
+            r#""jsonPath":".spec.name""#,
+        ] {
+            assert!(
+                generated_content.contains(expected),
+                "Generated content does not contain {}.",
+                expected
+            );
+        }
+
+        Ok(())
+    }
+
+    /// Tests that `generate_crds` writes one CRD per kind and a kustomization, serving additional versions in the same CRD,
+    /// and that `generate_crdgen_fixtures` writes the same CRDs for the crdgen tests.
+    #[test]
+    #[serial]
+    fn test_generate_crds() -> Result<(), AppError> {
+        let openapi_yaml = build_openapi_yaml(
+            "",
+            r#"
+paths: {}
+components:
+  schemas:
+    Cat:
+      type: object
+      required: [name]
+      x-kubernetes-operator-short-names: [ct]
+      properties:
+        uuid:
+          type: string
+        name:
+          type: string
+          x-kubernetes-operator-immutable: true
+        age:
+          type: integer
+    CatV1alpha1:
+      type: object
+      x-kubernetes-operator-version-of: Cat
+      x-kubernetes-operator-version: v1alpha1
+      properties:
+        name:
+          type: string
+"#,
+        );
+
+        let (dir, openapi_file_path) = create_temp_file("openapi.yaml", &openapi_yaml);
+        let output_path = dir.path().join("manifests").join("crds");
+
+        let openapi = read_openapi_spec(openapi_file_path.as_str())?;
+        let config = extract_config_from_openapi(&openapi)?;
+        let schemas = read_schemas(&openapi_file_path)?;
+
+        generate_crds(
+            output_path
+                .to_str()
+                .expect("Failed to convert output path to string"),
+            &schemas,
+            &config,
+        )?;
+
+        let mut files: Vec<String> = fs::read_dir(&output_path)?
+            .map(|entry| entry.map(|entry| entry.file_name().to_string_lossy().to_string()))
+            .collect::<Result<_, _>>()?;
+        files.sort();
+        assert_eq!(
+            files,
+            vec![
+                "cats.example.com.yaml".to_string(),
+                "kustomization.yaml".to_string()
+            ]
+        );
+        let kustomization = fs::read_to_string(output_path.join("kustomization.yaml"))?;
+        assert!(
+            kustomization.contains("  - cats.example.com.yaml"),
+            "Kustomization does not list the CRD."
+        );
+
+        let crd: serde_json::Value = serde_yaml::from_str(&fs::read_to_string(
+            output_path.join("cats.example.com.yaml"),
+        )?)?;
+        assert_eq!(crd["spec"]["names"]["kind"], "Cat");
+        assert_eq!(crd["spec"]["names"]["shortNames"][0], "ct");
+        assert_eq!(crd["spec"]["scope"], "Namespaced");
+        assert_eq!(crd["spec"]["conversion"]["strategy"], "Webhook");
+
+        let versions = crd["spec"]["versions"]
+            .as_array()
+            .expect("CRD versions are not a list");
+        assert_eq!(versions.len(), 2);
+        assert_eq!(versions[0]["name"], "v1");
+        assert_eq!(versions[0]["storage"], true);
+        assert_eq!(versions[1]["name"], "v1alpha1");
+        assert_eq!(versions[1]["storage"], false);
+
+        let spec = &versions[0]["schema"]["openAPIV3Schema"]["properties"]["spec"];
+        assert_eq!(spec["required"], serde_json::json!(["name"]));
+        assert_eq!(spec["properties"]["age"]["nullable"], true);
+        assert_eq!(
+            spec["properties"]["name"]["x-kubernetes-validations"][0]["rule"],
+            "self == oldSelf"
+        );
+        assert!(
+            spec["properties"].get("uuid").is_none(),
+            "The resource reference should only be part of the status."
+        );
+
+        let crdgen_path = dir.path().join("crdgen");
+        generate_crdgen_fixtures(
+            crdgen_path
+                .to_str()
+                .expect("Failed to convert crdgen path to string"),
+            &schemas,
+            &config,
+        )?;
+        let fixtures_path = crdgen_path.join("tests").join("crds");
+        let fixtures: Vec<String> = fs::read_dir(&fixtures_path)?
+            .map(|entry| entry.map(|entry| entry.file_name().to_string_lossy().to_string()))
+            .collect::<Result<_, _>>()?;
+        assert_eq!(fixtures, vec!["cats.example.com.yaml".to_string()]);
+        assert_eq!(
+            fs::read_to_string(fixtures_path.join("cats.example.com.yaml"))?,
+            fs::read_to_string(output_path.join("cats.example.com.yaml"))?,
+            "The crdgen tests have to compare against the CRDs in manifests/crds."
+        );
+
+        Ok(())
+    }
+
+    /// Tests that `generate_crds` writes the same files as `k8s-crdgen`, which derives the CRDs
+    /// with schemars from the generated types, for the spec in `tests/fixtures/schemars_crds`.
+    #[test]
+    #[serial]
+    fn test_generate_crds_matches_schemars() -> Result<(), AppError> {
+        let fixtures_path = Path::new(env!("CARGO_MANIFEST_DIR"))
+            .join("tests")
+            .join("fixtures")
+            .join("schemars_crds");
+        let openapi_file = fixtures_path.join("openapi.yaml");
+        let openapi_file = openapi_file.to_string_lossy();
+        let config = extract_config_from_openapi(&read_openapi_spec(&openapi_file)?)?;
+        let schemas = read_schemas(&openapi_file)?;
+
+        let dir = tempdir()?;
+        generate_crds(&dir.path().to_string_lossy(), &schemas, &config)?;
+
+        let mut files: Vec<String> = fs::read_dir(dir.path())?
+            .map(|entry| entry.map(|entry| entry.file_name().to_string_lossy().to_string()))
+            .collect::<Result<_, _>>()?;
+        files.sort();
+        assert_eq!(
+            files,
+            vec![
+                "cats.example.com.yaml".to_string(),
+                "geese.example.com.yaml".to_string(),
+                "kustomization.yaml".to_string()
+            ]
+        );
+        for file_name in files {
+            assert_eq!(
+                fs::read_to_string(dir.path().join(&file_name))?,
+                fs::read_to_string(fixtures_path.join(&file_name))?,
+                "{} does not match the one written by k8s-crdgen.",
+                file_name
+            );
+        }
+
+        Ok(())
+    }
+
+    /// Tests that `generate_helm_chart` writes a chart named after the operator, granting
+    /// cluster-scoped kinds through the ClusterRole and routing conversions to the release namespace,
+    /// and only serves the conversion webhook for kinds in additional versions.
+    #[test]
+    #[serial]
+    fn test_generate_helm_chart() -> Result<(), AppError> {
+        let openapi_yaml = build_openapi_yaml(
+            "",
+            r#"
+paths: {}
+components:
+  schemas:
+    Cat:
+      type: object
+      properties:
+        name:
+          type: string
+    CatV1alpha1:
+      type: object
+      x-kubernetes-operator-version-of: Cat
+      x-kubernetes-operator-version: v1alpha1
+      properties:
+        name:
+          type: string
+    Goose:
+      type: object
+      x-kubernetes-operator-scope: Cluster
+      properties:
+        name:
+          type: string
+"#,
+        );
+
+        let (dir, openapi_file_path) = create_temp_file("openapi.yaml", &openapi_yaml);
+        let output_path = dir.path().join("charts");
+
+        let openapi = read_openapi_spec(openapi_file_path.as_str())?;
+        let config = extract_config_from_openapi(&openapi)?;
+        let settings = get_deployment_settings(&openapi)?;
+        let schemas = read_schemas(&openapi_file_path)?;
+
+        generate_helm_chart(
+            output_path
+                .to_str()
+                .expect("Failed to convert output path to string"),
+            &schemas,
+            &config,
+            &settings,
+        )?;
+
+        let chart_path = output_path.join("test-operator");
+        let chart = fs::read_to_string(chart_path.join("Chart.yaml"))?;
+        assert!(
+            chart.contains("name: test-operator"),
+            "Chart is not named after the operator."
+        );
+        let values = fs::read_to_string(chart_path.join("values.yaml"))?;
+        assert!(
+            values.contains("  name: operator-secret"),
+            "Values do not default to the configured secret."
+        );
+        assert!(
+            chart_path
+                .join("templates")
+                .join("conversion-webhook.yaml")
+                .exists(),
+            "The conversion webhook is missing for a kind served in additional versions."
+        );
+
+        let role = fs::read_to_string(chart_path.join("templates").join("role.yaml"))?;
+        assert!(
+            role.contains("      - cats/status"),
+            "Role does not grant cats."
+        );
+        assert!(
+            role.contains("{{- range $namespace := .Values.watchNamespaces }}")
+                && role.contains("kind: ClusterRole"),
+            "Role is not rendered per watch namespace, or cluster-wide without them."
+        );
+        let deployment = fs::read_to_string(chart_path.join("templates").join("deployment.yaml"))?;
+        assert!(
+            deployment.contains(r#"value: {{ join "," .Values.watchNamespaces | quote }}"#),
+            "The watch namespaces are not passed to the operator."
+        );
+        assert!(
+            values.contains("allowDeleteWhenPaused: false")
+                && deployment.contains("value: {{ .Values.allowDeleteWhenPaused | quote }}"),
+            "Deleting paused resources is not configurable through the values."
+        );
+        assert!(
+            !role.contains("geese"),
+            "Role grants a cluster-scoped kind."
+        );
+        let cluster_role =
+            fs::read_to_string(chart_path.join("templates").join("clusterrole.yaml"))?;
+        assert!(
+            cluster_role.contains("      - geese/status"),
+            "ClusterRole does not grant geese."
+        );
+        assert!(
+            cluster_role.contains("{{- if .Values.installCrds }}"),
+            "ClusterRole always writes CRDs."
+        );
+
+        let crd = fs::read_to_string(
+            chart_path
+                .join("templates")
+                .join("crds")
+                .join("cats.example.com.yaml"),
+        )?;
+        assert!(
+            crd.contains("{{- if .Values.crds.enabled }}"),
+            "CRD is not guarded by crds.enabled."
+        );
+        assert!(
+            crd.contains(r#"namespace: '{{ include "operator.namespace" . }}'"#),
+            "Conversions are not routed to the release namespace."
+        );
+        assert!(
+            deployment.contains("containerPort: 8443")
+                && deployment.contains("- name: webhook-certs"),
+            "The deployment does not serve the conversion webhook."
+        );
+
+        // Without additional versions there is no conversion webhook to serve.
+        let mut schemas = schemas;
+        schemas.remove("CatV1alpha1");
+        generate_helm_chart(
+            output_path
+                .to_str()
+                .expect("Failed to convert output path to string"),
+            &schemas,
+            &config,
+            &settings,
+        )?;
+        assert!(
+            !chart_path
+                .join("templates")
+                .join("conversion-webhook.yaml")
+                .exists(),
+            "The conversion webhook is left over without additional versions."
+        );
+        let deployment = fs::read_to_string(chart_path.join("templates").join("deployment.yaml"))?;
+        assert!(
+            !deployment.contains("8443") && !deployment.contains("webhook-certs"),
+            "The deployment serves a conversion webhook without additional versions."
+        );
+
+        Ok(())
+    }
+
+    /// Tests that `generate_bundle` writes a ClusterServiceVersion owning every version of the CRDs,
+    /// with the examples, permissions and conversion webhook of the operator.
+    #[test]
+    #[serial]
+    fn test_generate_bundle() -> Result<(), AppError> {
+        let openapi_yaml = build_openapi_yaml(
+            "",
+            r#"
+paths: {}
+components:
+  examples:
+    Cat:
+      value:
+        uuid: 123e4567-e89b-12d3-a456-426614174000
+        name: Fluffy
+  schemas:
+    Cat:
+      type: object
+      description: A cat of the shelter.
+      properties:
+        uuid:
+          type: string
+        name:
+          type: string
+    CatV1alpha1:
+      type: object
+      x-kubernetes-operator-version-of: Cat
+      x-kubernetes-operator-version: v1alpha1
+      properties:
+        name:
+          type: string
+"#,
+        );
+
+        let (dir, openapi_file_path) = create_temp_file("openapi.yaml", &openapi_yaml);
+        let output_path = dir.path().join("bundle");
+
+        let openapi = read_openapi_spec(openapi_file_path.as_str())?;
+        let config = extract_config_from_openapi(&openapi)?;
+        let settings = get_deployment_settings(&openapi)?;
+        let schemas = read_schemas(&openapi_file_path)?;
+        let examples = openapi
+            .components
+            .ok_or_else(|| AppError::Other("No components found in OpenAPI spec".to_string()))?
+            .examples;
+
+        generate_bundle(
+            output_path
+                .to_str()
+                .expect("Failed to convert output path to string"),
+            &schemas,
+            examples.into_iter().collect(),
+            &config,
+            &settings,
+        )?;
+
+        assert!(output_path.join("bundle.Dockerfile").exists());
+        let annotations =
+            fs::read_to_string(output_path.join("metadata").join("annotations.yaml"))?;
+        assert!(
+            annotations.contains("operators.operatorframework.io.bundle.package.v1: test-operator"),
+            "Bundle annotations do not name the package."
+        );
+        let crd: serde_json::Value = serde_yaml::from_str(&fs::read_to_string(
+            output_path.join("manifests").join("cats.example.com.yaml"),
+        )?)?;
+        assert!(
+            crd["spec"].get("conversion").is_none(),
+            "The conversion of the CRD is left to OLM."
+        );
+
+        let csv: serde_json::Value = serde_yaml::from_str(&fs::read_to_string(
+            output_path
+                .join("manifests")
+                .join("test-operator.clusterserviceversion.yaml"),
+        )?)?;
+        assert_eq!(csv["metadata"]["name"], "test-operator.v0.1.0");
+        assert_eq!(csv["spec"]["provider"]["name"], "Test Author");
+
+        let examples: serde_json::Value = serde_json::from_str(
+            csv["metadata"]["annotations"]["alm-examples"]
+                .as_str()
+                .expect("alm-examples is not a string"),
+        )?;
+        assert_eq!(examples[0]["kind"], "Cat");
+        assert_eq!(examples[0]["metadata"]["name"], "example-fluffy");
+        assert!(
+            examples[0]["spec"].get("uuid").is_none(),
+            "The resource reference should not be part of the example."
+        );
+
+        let owned = &csv["spec"]["customresourcedefinitions"]["owned"];
+        assert_eq!(owned[0]["version"], "v1");
+        assert_eq!(owned[0]["description"], "A cat of the shelter.");
+        assert_eq!(owned[1]["version"], "v1alpha1");
+
+        let install = &csv["spec"]["install"]["spec"];
+        assert_eq!(
+            install["permissions"][0]["rules"][0]["resources"][0],
+            "cats"
+        );
+        assert_eq!(install["deployments"][0]["name"], "test-operator");
+        assert_eq!(
+            csv["spec"]["webhookdefinitions"][0]["conversionCRDs"][0],
+            "cats.example.com"
+        );
+        let install_modes = csv["spec"]["installModes"]
+            .as_array()
+            .expect("Install modes are not a list");
+        assert!(
+            install_modes
+                .iter()
+                .all(|mode| (mode["type"] == "AllNamespaces") == mode["supported"]),
+            "Conversion webhooks require the operator to watch all namespaces."
+        );
+
+        Ok(())
+    }
+
+    /// Tests that `execute` generates the kustomize base of the manifests and keeps existing overlays.
+    #[test]
+    #[serial]
+    fn test_execute_generates_kustomize_base_and_overlays() -> Result<(), AppError> {
+        let openapi_yaml = build_openapi_yaml(
+            "",
+            r#"
+paths: {}
+components:
+  schemas:
+    Cat:
+      type: object
+      properties:
+        name:
+          type: string
+"#,
+        );
+
+        let (dir, openapi_file) = create_temp_file("openapi.yaml", &openapi_yaml);
+        let manifests_path = dir.path().join("manifests");
+        for directory in ["rbac", "operator", "examples"] {
+            fs::create_dir_all(manifests_path.join(directory))?;
+        }
+        fs::create_dir_all(dir.path().join("crdgen").join("src"))?;
+        let dev_overlay_path = manifests_path.join("overlays").join("dev");
+        fs::create_dir_all(&dev_overlay_path)?;
+        fs::write(
+            dev_overlay_path.join("kustomization.yaml"),
+            "resources: []\n",
+        )?;
+
+        let targets = Targets {
+            all: false,
+            manifests: true,
+            controllers: false,
+            types: false,
+            crds: false,
+            helm: false,
+            bundle: false,
+        };
+        execute(
+            &dir.path().to_string_lossy().to_string(),
+            &openapi_file,
+            &targets,
+        )?;
+
+        let base = fs::read_to_string(manifests_path.join("base").join("kustomization.yaml"))?;
+        for resource in ["../crds", "../rbac", "../operator"] {
+            assert!(
+                base.contains(&format!("  - {}", resource)),
+                "Base does not include {}.",
+                resource
+            );
+        }
+        for directory in ["crds", "rbac", "operator"] {
+            assert!(
+                manifests_path
+                    .join(directory)
+                    .join("kustomization.yaml")
+                    .exists(),
+                "Kustomization of {} is missing.",
+                directory
+            );
+        }
+        assert_eq!(
+            fs::read_to_string(dev_overlay_path.join("kustomization.yaml"))?,
+            "resources: []\n",
+            "Existing overlay was overwritten."
+        );
+        let prod = fs::read_to_string(
+            manifests_path
+                .join("overlays")
+                .join("prod")
+                .join("kustomization.yaml"),
+        )?;
+        assert!(
+            prod.contains("        name: operator-secret"),
+            "Prod overlay does not drop the placeholder secret."
+        );
+
+        Ok(())
+    }
+
+    /// Tests that `execute` hardens the operator deployment by default and adds the configured
+    /// PodDisruptionBudget and NetworkPolicy.
+    #[test]
+    #[serial]
+    fn test_execute_generates_hardened_deployment() -> Result<(), AppError> {
+        let openapi_yaml = build_openapi_yaml(
+            r#"
+  x-kubernetes-operator-resources:
+    limits:
+      memory: 256Mi
+  x-kubernetes-operator-pod-disruption-budget:
+    minAvailable: 1
+  x-kubernetes-operator-network-policy: true
+  x-kubernetes-operator-network-policy-api-server-cidrs: [172.18.0.2/32]"#,
+            r#"
+servers:
+  - url: https://10.0.0.1/api
+paths: {}
+components:
+  schemas:
+    Cat:
+      type: object
+      properties:
+        name:
+          type: string
+"#,
+        );
+
+        let (dir, openapi_file) = create_temp_file("openapi.yaml", &openapi_yaml);
+        let manifests_path = dir.path().join("manifests");
+        for directory in ["rbac", "operator", "examples"] {
+            fs::create_dir_all(manifests_path.join(directory))?;
+        }
+        fs::create_dir_all(dir.path().join("crdgen").join("src"))?;
+
+        let targets = Targets {
+            all: false,
+            manifests: true,
+            controllers: false,
+            types: false,
+            crds: false,
+            helm: false,
+            bundle: false,
+        };
+        execute(
+            &dir.path().to_string_lossy().to_string(),
+            &openapi_file,
+            &targets,
+        )?;
+
+        let operator_path = manifests_path.join("operator");
+        let deployment: serde_json::Value =
+            serde_yaml::from_str(&fs::read_to_string(operator_path.join("deployment.yaml"))?)?;
+        let pod_spec = &deployment["spec"]["template"]["spec"];
+        assert_eq!(pod_spec["securityContext"]["runAsNonRoot"], true);
+        assert_eq!(
+            pod_spec["securityContext"]["seccompProfile"]["type"],
+            "RuntimeDefault"
+        );
+        let container = &pod_spec["containers"][0];
+        assert_eq!(container["securityContext"]["readOnlyRootFilesystem"], true);
+        assert_eq!(
+            container["securityContext"]["capabilities"]["drop"][0],
+            "ALL"
+        );
+        assert_eq!(
+            container["resources"],
+            serde_json::json!({"limits": {"memory": "256Mi"}}),
+            "Resources are not taken from the configuration."
+        );
+
+        let budget: serde_json::Value = serde_yaml::from_str(&fs::read_to_string(
+            operator_path.join("pod-disruption-budget.yaml"),
+        )?)?;
+        assert_eq!(budget["spec"]["minAvailable"], 1);
+        let network_policy: serde_json::Value = serde_yaml::from_str(&fs::read_to_string(
+            operator_path.join("network-policy.yaml"),
+        )?)?;
+        let egress = network_policy["spec"]["egress"]
+            .as_array()
+            .expect("NetworkPolicy egress is not a list");
+        for rule in egress {
+            assert!(
+                rule["ports"]
+                    .as_array()
+                    .is_some_and(|ports| ports.iter().all(|port| port["port"] == 53))
+                    || rule["to"].as_array().is_some_and(|to| !to.is_empty()),
+                "Egress other than DNS is not restricted to a destination: {}",
+                rule
+            );
+        }
+        let api_server_rule = &egress[1];
+        assert_eq!(api_server_rule["to"][0]["ipBlock"]["cidr"], "172.18.0.2/32");
+        let api_rule = &egress[2];
+        assert_eq!(api_rule["to"][0]["ipBlock"]["cidr"], "10.0.0.1/32");
+        assert_eq!(api_rule["ports"][0]["port"], 443);
+
+        let kustomization = fs::read_to_string(operator_path.join("kustomization.yaml"))?;
+        for resource in ["pod-disruption-budget.yaml", "network-policy.yaml"] {
+            assert!(
+                kustomization.contains(resource),
+                "Kustomization does not include {}.",
+                resource
+            );
+        }
+
+        Ok(())
+    }
+
+    /// Tests that `get_deployment_settings` requires CIDRs for the NetworkPolicy egress to the
+    /// Kubernetes API server and to a remote API addressed by host name.
+    #[test]
+    #[serial]
+    fn test_get_deployment_settings_requires_network_policy_cidrs() -> Result<(), AppError> {
+        let openapi_yaml = |extensions: &str| {
+            format!(
+                r#"
+openapi: 3.0.0
+info:
+  title: Test API
+  version: 1.0.0
+  x-kubernetes-operator-network-policy: true
+{}
+servers:
+  - url: https://api.example.com/v1
+paths: {{}}
+"#,
+                extensions
+            )
+        };
+
+        for (extensions, expected) in [
+            (
+                "",
+                "requires 'x-kubernetes-operator-network-policy-api-server-cidrs'",
+            ),
+            (
+                "  x-kubernetes-operator-network-policy-api-server-cidrs: [172.18.0.2/32]",
+                "cannot match the host name api.example.com",
+            ),
+            (
+                "  x-kubernetes-operator-network-policy-api-server-cidrs: [172.18.0.2]",
+                "must be a non-empty list of CIDRs",
+            ),
+            (
+                "  x-kubernetes-operator-network-policy-api-server-cidrs: [172.18.0.2/32]\n  x-kubernetes-operator-network-policy-remote-cidrs: []",
+                "must be a non-empty list of CIDRs",
+            ),
+        ] {
+            let (_dir, openapi_file_path) =
+                create_temp_file("openapi.yaml", &openapi_yaml(extensions));
+            let openapi = read_openapi_spec(openapi_file_path.as_str())?;
+            match get_deployment_settings(&openapi) {
+                Err(AppError::ConfigError(message)) => assert!(
+                    message.contains(expected),
+                    "Unexpected error message: {}",
+                    message
+                ),
+                Err(e) => panic!("Expected ConfigError, got {:?}", e),
+                Ok(_) => panic!("Expected ConfigError containing '{}'", expected),
+            }
+        }
+
+        let (_dir, openapi_file_path) = create_temp_file(
+            "openapi.yaml",
+            &openapi_yaml(
+                "  x-kubernetes-operator-network-policy-api-server-cidrs: [172.18.0.2/32]\n  x-kubernetes-operator-network-policy-remote-cidrs: [203.0.113.0/24]",
+            ),
+        );
+        let openapi = read_openapi_spec(openapi_file_path.as_str())?;
+        assert!(
+            get_deployment_settings(&openapi).is_ok(),
+            "Host names are allowed with remote CIDRs."
+        );
+
+        Ok(())
+    }
+
+    /// Tests that `execute` names the manifests after the operator and installs them into the
+    /// configured namespace.
+    #[test]
+    #[serial]
+    fn test_execute_names_manifests_after_operator() -> Result<(), AppError> {
+        let openapi_yaml = build_openapi_yaml(
+            "  x-kubernetes-operator-namespace: cats",
+            r#"
+paths: {}
+components:
+  schemas:
+    Cat:
+      type: object
+      properties:
+        name:
+          type: string
+"#,
+        );
+
+        let (dir, openapi_file) = create_temp_file("openapi.yaml", &openapi_yaml);
+        let manifests_path = dir.path().join("manifests");
+        for directory in ["rbac", "operator", "examples"] {
+            fs::create_dir_all(manifests_path.join(directory))?;
+        }
+        fs::create_dir_all(dir.path().join("crdgen").join("src"))?;
+
+        let targets = Targets {
+            all: false,
+            manifests: true,
+            controllers: false,
+            types: false,
+            crds: false,
+            helm: false,
+            bundle: false,
+        };
+        execute(
+            &dir.path().to_string_lossy().to_string(),
+            &openapi_file,
+            &targets,
+        )?;
+
+        let read_manifest =
+            |directory: &str, file_name: &str| -> Result<serde_json::Value, AppError> {
+                Ok(serde_yaml::from_str(&fs::read_to_string(
+                    manifests_path.join(directory).join(file_name),
+                )?)?)
+            };
+        let deployment = read_manifest("operator", "deployment.yaml")?;
+        assert_eq!(deployment["metadata"]["name"], "test-operator");
+        assert_eq!(deployment["metadata"]["namespace"], "cats");
+        assert_eq!(
+            deployment["spec"]["template"]["spec"]["serviceAccountName"],
+            "test-operator-service-account"
+        );
+        assert_eq!(
+            read_manifest("operator", "secret.yaml")?["metadata"]["namespace"],
+            "cats"
+        );
+        let service_account = read_manifest("rbac", "serviceaccount.yaml")?;
+        assert_eq!(
+            service_account["metadata"]["name"],
+            "test-operator-service-account"
+        );
+        assert_eq!(service_account["metadata"]["namespace"], "cats");
+        let cluster_role_binding = read_manifest("rbac", "clusterrolebinding.yaml")?;
+        assert_eq!(
+            cluster_role_binding["metadata"]["name"],
+            "test-operator-cluster-role-binding"
+        );
+        assert_eq!(cluster_role_binding["subjects"][0]["namespace"], "cats");
+        assert_eq!(
+            cluster_role_binding["roleRef"]["name"],
+            "test-operator-cluster-role"
+        );
+
+        Ok(())
+    }
+
+    /// Tests that `execute` synthesizes examples of kinds without one from their schema and keeps
+    /// the examples of the spec.
+    #[test]
+    #[serial]
+    fn test_execute_synthesizes_examples_from_schemas() -> Result<(), AppError> {
+        let openapi_yaml = build_openapi_yaml(
+            "",
+            r#"
+paths: {}
+components:
+  examples:
+    Dog:
+      value:
+        name: Rex
+  schemas:
+    Cat:
+      type: object
+      required: [name, age, color]
+      properties:
+        uuid:
+          type: string
+          format: uuid
+          readOnly: true
+        name:
+          type: string
+        age:
+          type: integer
+          minimum: 1
+        color:
+          type: string
+          enum: [black, white]
+        weight:
+          type: number
+          example: 4.5
+        toys:
+          type: array
+          items:
+            type: string
+    Dog:
+      type: object
+      properties:
+        name:
+          type: string
+"#,
+        );
+
+        let (dir, openapi_file) = create_temp_file("openapi.yaml", &openapi_yaml);
+        let manifests_path = dir.path().join("manifests");
+        for directory in ["rbac", "operator", "examples"] {
+            fs::create_dir_all(manifests_path.join(directory))?;
+        }
+        fs::create_dir_all(dir.path().join("crdgen").join("src"))?;
+
+        let targets = Targets {
+            all: false,
+            manifests: true,
+            controllers: false,
+            types: false,
+            crds: false,
+            helm: false,
+            bundle: false,
+        };
+        execute(
+            &dir.path().to_string_lossy().to_string(),
+            &openapi_file,
+            &targets,
+        )?;
+
+        let examples_path = manifests_path.join("examples");
+        let cat: serde_json::Value =
+            serde_yaml::from_str(&fs::read_to_string(examples_path.join("cat.yaml"))?)?;
+        assert_eq!(cat["kind"], "Cat");
+        assert_eq!(cat["metadata"]["name"], "example-cat");
+        assert_eq!(
+            cat["spec"],
+            serde_json::json!({
+                "name": "cat",
+                "age": 1,
+                "color": "black",
+                "weight": 4.5,
+                "toys": ["toy"],
+            }),
+            "Example is not synthesized from the schema."
+        );
+        let dog: serde_json::Value =
+            serde_yaml::from_str(&fs::read_to_string(examples_path.join("dog.yaml"))?)?;
+        assert_eq!(
+            dog["metadata"]["name"], "example-rex",
+            "Example of the spec was replaced."
+        );
+
+        Ok(())
+    }
+
+    /// Tests that the examples `execute` synthesizes stay within the bounds of their schema and
+    /// leave out optional strings which have to match a pattern.
+    #[test]
+    #[serial]
+    fn test_execute_synthesizes_examples_within_schema_bounds() -> Result<(), AppError> {
+        let openapi_yaml = build_openapi_yaml(
+            "",
+            r#"
+paths: {}
+components:
+  schemas:
+    Cat:
+      type: object
+      properties:
+        name:
+          type: string
+          minLength: 5
+          maxLength: 8
+        lives:
+          type: integer
+          minimum: 0
+          exclusiveMinimum: true
+          maximum: 9
+          exclusiveMaximum: true
+        weight:
+          type: number
+          minimum: 0
+          exclusiveMinimum: true
+          maximum: 10
+          exclusiveMaximum: true
+        temperature:
+          type: number
+          maximum: 0
+          exclusiveMaximum: true
+        chip:
+          type: string
+          pattern: '^[A-Z]{3}[0-9]{6}$'
+        tags:
+          type: array
+          items:
+            type: string
+            pattern: '^[a-z]+$'
+"#,
+        );
+
+        let (dir, openapi_file) = create_temp_file("openapi.yaml", &openapi_yaml);
+        let manifests_path = dir.path().join("manifests");
+        for directory in ["rbac", "operator", "examples"] {
+            fs::create_dir_all(manifests_path.join(directory))?;
+        }
+        fs::create_dir_all(dir.path().join("crdgen").join("src"))?;
+
+        let targets = Targets {
+            all: false,
+            manifests: true,
+            controllers: false,
+            types: false,
+            crds: false,
+            helm: false,
+            bundle: false,
+        };
+        execute(
+            &dir.path().to_string_lossy().to_string(),
+            &openapi_file,
+            &targets,
+        )?;
+
+        let cat: serde_json::Value = serde_yaml::from_str(&fs::read_to_string(
+            manifests_path.join("examples").join("cat.yaml"),
+        )?)?;
+        let spec = cat["spec"]
+            .as_object()
+            .expect("Example spec is not an object");
+        assert!(
+            !spec.contains_key("chip") && !spec.contains_key("tags"),
+            "Optional strings with a pattern are synthesized: {:?}",
+            spec
+        );
+
+        let schemas = read_schemas(&openapi_file)?;
+        let schema = schemas.get("Cat").expect("Cat schema is missing");
+        let openapiv3::SchemaKind::Type(openapiv3::Type::Object(object)) = &schema.schema_kind
+        else {
+            panic!("Cat schema is not an object");
+        };
+        for (name, value) in spec {
+            let Some(openapiv3::ReferenceOr::Item(property)) = object.properties.get(name) else {
+                panic!("{} is not a property of Cat", name);
+            };
+            let within_bounds = match &property.schema_kind {
+                openapiv3::SchemaKind::Type(openapiv3::Type::String(string)) => {
+                    let length = value.as_str().map_or(0, |value| value.chars().count());
+                    string.min_length.is_none_or(|min| length >= min)
+                        && string.max_length.is_none_or(|max| length <= max)
+                }
+                openapiv3::SchemaKind::Type(openapiv3::Type::Integer(integer)) => {
+                    let value = value.as_i64().expect("Integer example is not an integer");
+                    integer.minimum.is_none_or(|min| {
+                        value > min || (!integer.exclusive_minimum && value == min)
+                    }) && integer.maximum.is_none_or(|max| {
+                        value < max || (!integer.exclusive_maximum && value == max)
+                    })
+                }
+                openapiv3::SchemaKind::Type(openapiv3::Type::Number(number)) => {
+                    let value = value.as_f64().expect("Number example is not a number");
+                    number.minimum.is_none_or(|min| {
+                        value > min || (!number.exclusive_minimum && value == min)
+                    }) && number.maximum.is_none_or(|max| {
+                        value < max || (!number.exclusive_maximum && value == max)
+                    })
+                }
+                _ => true,
+            };
+            assert!(
+                within_bounds,
+                "Example of {} is out of bounds: {}",
+                name, value
+            );
+        }
+        assert_eq!(spec.len(), 4, "Example lacks fields: {:?}", spec);
+
+        Ok(())
+    }
+
+    /// Tests that the examples `execute` synthesizes keep required strings with a pattern, so
+    /// they pass the validation of the generated CRD.
+    #[test]
+    #[serial]
+    fn test_execute_synthesizes_required_pattern_strings() -> Result<(), AppError> {
+        let openapi_yaml = build_openapi_yaml(
+            "",
+            r#"
+paths: {}
+components:
+  schemas:
+    Cat:
+      type: object
+      required: [name, chip]
+      properties:
+        name:
+          type: string
+        chip:
+          type: string
+          pattern: '^[A-Z]{3}[0-9]{6}$'
+        tattoo:
+          type: string
+          pattern: '^[A-Z]+$'
+"#,
+        );
+
+        let (dir, openapi_file) = create_temp_file("openapi.yaml", &openapi_yaml);
+        let manifests_path = dir.path().join("manifests");
+        for directory in ["rbac", "operator", "examples"] {
+            fs::create_dir_all(manifests_path.join(directory))?;
+        }
+        fs::create_dir_all(dir.path().join("crdgen").join("src"))?;
+
+        let targets = Targets {
+            all: false,
+            manifests: true,
+            controllers: false,
+            types: false,
+            crds: true,
+            helm: false,
+            bundle: false,
+        };
+        execute(
+            &dir.path().to_string_lossy().to_string(),
+            &openapi_file,
+            &targets,
+        )?;
+
+        let cat: serde_json::Value = serde_yaml::from_str(&fs::read_to_string(
+            manifests_path.join("examples").join("cat.yaml"),
+        )?)?;
+        let crd: serde_json::Value = serde_yaml::from_str(&fs::read_to_string(
+            manifests_path.join("crds").join("cats.example.com.yaml"),
+        )?)?;
+        let required = crd["spec"]["versions"][0]["schema"]["openAPIV3Schema"]["properties"]
+            ["spec"]["required"]
+            .as_array()
+            .expect("CRD has no required spec fields");
+        assert_eq!(
+            required,
+            &vec![serde_json::json!("chip"), serde_json::json!("name")]
+        );
+        for field in required {
+            let field = field.as_str().expect("Required field is not a string");
+            assert!(
+                cat["spec"][field].is_string(),
+                "Example lacks the required {}: {}",
+                field,
+                cat["spec"]
+            );
+        }
+        assert!(
+            cat["spec"].get("tattoo").is_none(),
+            "Optional string with a pattern is synthesized: {}",
+            cat["spec"]
+        );
+
+        Ok(())
+    }
+
+    /// Tests that `generate_types` turns immutable and validated properties into CEL rules.
+    #[test]
+    #[serial]
+    fn test_generate_types_with_validations() -> Result<(), AppError> {
+        let openapi_yaml = r#"
+openapi: 3.0.0
+info:
+  title: Test API
+  version: 1.0.0
+paths: {}
+components:
+  schemas:
+    Cluster:
+      type: object
+      required: [region]
+      properties:
+        region:
+          type: string
+          x-kubernetes-operator-immutable: true
+        replicas:
+          type: integer
+          x-kubernetes-operator-validation: self >= 1
+"#;
+
+        let (dir, openapi_file_path) = create_temp_file("openapi.yaml", openapi_yaml);
+        let output_path = dir.path().join("src").join("types");
+        fs::create_dir_all(&output_path)?;
+
+        let schemas = read_schemas(&openapi_file_path)?;
+
+        let types_directory = output_path
+            .to_str()
+            .expect("Failed to convert output path to string");
+
+        generate_types(types_directory, &schemas, "uuid", "example.com", "v1")?;
+
+        let generated_content = fs::read_to_string(output_path.join("cluster.rs"))?;
+        for expected in [
+            r#"#[schemars(schema_with = "region_schema")]"#,
+            r#"{"message":"region is immutable","rule":"self == oldSelf"}"#,
+            r#"#[schemars(schema_with = "replicas_schema")]"#,
+            r#"{"rule":"self >= 1"}"#,
+        ] {
+            assert!(
+                generated_content.contains(expected),
+                "Generated content does not contain {}.",
+                expected
+            );
+        }
+
+        Ok(())
+    }
+
+    /// Tests that `generate_types` uses the configured group and version unless a schema overrides them.
+    #[test]
+    #[serial]
+    fn test_generate_types_with_schema_group_version() -> Result<(), AppError> {
+        let openapi_yaml = r#"
+openapi: 3.0.0
+info:
+  title: Test API
+  version: 1.0.0
+paths: {}
+components:
+  schemas:
+    Cat:
+      type: object
+      properties:
+        name:
+          type: string
+    Dog:
+      type: object
+      x-kubernetes-operator-api-group: pets.example.org
+      x-kubernetes-operator-api-version: v2
+      properties:
+        name:
+          type: string
+"#;
+
+        let (dir, openapi_file_path) = create_temp_file("openapi.yaml", openapi_yaml);
+        let output_path = dir.path().join("src").join("types");
+        fs::create_dir_all(&output_path)?;
+
+        let schemas = read_schemas(&openapi_file_path)?;
+
+        let types_directory = output_path
+            .to_str()
+            .expect("Failed to convert output path to string");
+
+        generate_types(types_directory, &schemas, "uuid", "example.org", "v1beta1")?;
+
+        let cat = fs::read_to_string(output_path.join("cat.rs"))?;
+        assert!(
+            cat.contains(r#"group = "example.org""#) && cat.contains(r#"version = "v1beta1""#),
+            "Type without overrides does not use the configured group and version."
+        );
+        let dog = fs::read_to_string(output_path.join("dog.rs"))?;
+        assert!(
+            dog.contains(r#"group = "pets.example.org""#) && dog.contains(r#"version = "v2""#),
+            "Type does not use the group and version of its schema."
+        );
+
+        Ok(())
+    }
+
+    /// Tests that `generate_types` generates another version of a kind with conversions to its hub type.
+    #[test]
+    #[serial]
+    fn test_generate_types_with_additional_version() -> Result<(), AppError> {
+        let openapi_yaml = r#"
+openapi: 3.0.0
+info:
+  title: Test API
+  version: 1.0.0
+paths: {}
+components:
+  schemas:
+    Cat:
+      type: object
+      properties:
+        name:
+          type: string
+        age:
+          type: integer
+    CatV1alpha1:
+      type: object
+      x-kubernetes-operator-version-of: Cat
+      x-kubernetes-operator-version: v1alpha1
+      properties:
+        name:
+          type: string
+"#;
+
+        let (dir, openapi_file_path) = create_temp_file("openapi.yaml", openapi_yaml);
+        let output_path = dir.path().join("src").join("types");
+        fs::create_dir_all(&output_path)?;
+
+        let schemas = read_schemas(&openapi_file_path)?;
+
+        let types_directory = output_path
+            .to_str()
+            .expect("Failed to convert output path to string");
+
+        generate_types(types_directory, &schemas, "uuid", "example.com", "v1")?;
+
+        let generated_content = fs::read_to_string(output_path.join("catv1alpha1.rs"))?;
+        for expected in [
+            r#"version = "v1alpha1""#,
+            r#"kind = "Cat""#,
+            r#"root = "CatV1alpha1""#,
+            r#"plural = "cats""#,
+            "impl From<CatV1alpha1Spec> for CatSpec",
+            "impl From<CatSpec> for CatV1alpha1Spec",
+        ] {
+            assert!(
+                generated_content.contains(expected),
+                "Generated content does not contain {}.",
+                expected
+            );
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    #[serial]
+    fn test_parsing_json_spec() -> Result<(), AppError> {
+        let openapi_json = r#"{
+    "openapi": "3.0.0",
+    "info": {
+        "title": "Test API",
+        "version": "1.0.0",
+        "x-kubernetes-operator-api-group": "testgroup",
+        "x-kubernetes-operator-api-version": "v1",
+        "x-kubernetes-operator-resource-ref": "uuid",
+        "x-kubernetes-operator-status-ref": "status",
+        "x-kubernetes-operator-include-tags": ["user"],
+        "x-kubernetes-operator-example-metadata-spec-field-ref": "metadata"
+    },
+    "paths": {},
+    "components": {
+        "schemas": {
+            "User": {
+                "type": "object",
+                "properties": {
+                    "id": {
+                        "type": "integer",
+                        "format": "int64"
+                    },
+                    "name": {
+                        "type": "string"
+                    },
+                    "email": {
+                        "type": "string",
+                        "format": "email"
+                    }
+                },
+                "required": ["id"]
+            }
+        }
+    }
+}"#;
+
+        let (dir, openapi_file) = create_temp_file("openapi.json", openapi_json);
+        let output_path = dir.path().join("src").join("types");
+        fs::create_dir_all(&output_path)?;
+
+        let schemas = read_schemas(&openapi_file)?;
 
         let operator_resource_ref = "id".to_string();
         let types_directory = output_path
             .to_str()
             .expect("Failed to convert output path to string");
 
-        generate_types(types_directory, &schemas, &operator_resource_ref)?;
+        generate_types(
+            types_directory,
+            &schemas,
+            &operator_resource_ref,
+            "example.com",
+            "v1",
+        )?;
 
         let type_file = output_path.join("user.rs");
         assert!(type_file.exists(), "Type file was not created.");
@@ -23,6 +23,7 @@ mod tests {
             "KUBERNETES_OPERATOR_EXAMPLE_METADATA_SPEC_FIELD_REF",
             "test-field-ref",
         );
+        env::set_var("KUBERNETES_OPERATOR_NAMESPACE", "test-namespace");
     }
 
     /// Clears the environment variables set for testing.
@@ -34,6 +35,7 @@ mod tests {
         env::remove_var("KUBERNETES_OPERATOR_RESOURCE_REF");
         env::remove_var("KUBERNETES_OPERATOR_INCLUDE_TAGS");
         env::remove_var("KUBERNETES_OPERATOR_EXAMPLE_METADATA_SPEC_FIELD_REF");
+        env::remove_var("KUBERNETES_OPERATOR_NAMESPACE");
     }
 
     /// Tests that the hydrate command correctly updates the OpenAPI spec with configuration values.
@@ -118,6 +120,15 @@ info:
             .unwrap(),
             "test-field-ref"
         );
+        assert_eq!(
+            info.get(YamlValue::String(
+                "x-kubernetes-operator-namespace".to_string()
+            ))
+            .unwrap()
+            .as_str()
+            .unwrap(),
+            "test-namespace"
+        );
 
         drop(dir);
         clear_env();
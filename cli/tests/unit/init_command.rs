@@ -133,6 +133,7 @@ mod tests {
             "operator/src/main.rs",
             "operator/src/cli.rs",
             "operator/src/errors.rs",
+            "operator/src/health.rs",
             "operator/src/controllers/mod.rs",
             "operator/src/types/mod.rs",
             "tests/src/utils/mod.rs",